@@ -2,23 +2,162 @@ use clap::{App, Arg};
 use fft::Fft;
 use num_complex::Complex;
 use rand::{distributions::Standard, Rng};
+use std::time::{Duration, Instant};
 
-fn main() {
-    let matches = App::new("fft-prof")
-        .arg(Arg::with_name("size").takes_value(true).required(true))
-        .get_matches();
+/// One size's worth of timed runs, already reduced to the numbers we report.
+struct Measurement {
+    size: usize,
+    min: Duration,
+    median: Duration,
+}
+
+impl Measurement {
+    /// `5 * N * log2(N)`, the standard FLOP-equivalent estimate for a radix-2-ish
+    /// complex FFT, divided by the measured time to get a throughput figure.
+    fn gflops(&self, elapsed: Duration) -> f64 {
+        let flops = 5.0 * self.size as f64 * (self.size as f64).log2();
+        flops / elapsed.as_secs_f64() / 1e9
+    }
+}
 
-    let size = usize::from_str_radix(matches.value_of("size").unwrap(), 10).unwrap();
-    let mut fft = fft::Fft32::new(size);
+fn parse_sizes(spec: &str) -> Vec<usize> {
+    if let Some((start, end)) = spec.split_once("..") {
+        let start: usize = start.parse().expect("range start must be a number");
+        let end: usize = end.parse().expect("range end must be a number");
+        (start..=end).collect()
+    } else {
+        spec.split(',')
+            .map(|s| s.trim().parse().expect("size must be a number"))
+            .collect()
+    }
+}
+
+fn is_power_of_two_friendly(size: usize) -> bool {
+    size.is_power_of_two()
+}
 
-    let mut input = rand::thread_rng()
+fn random_input(size: usize) -> Vec<Complex<f32>> {
+    rand::thread_rng()
         .sample_iter(&Standard)
         .zip(rand::thread_rng().sample_iter(&Standard))
         .take(size)
         .map(|(x, y)| Complex::new(x, y))
-        .collect::<Vec<_>>();
+        .collect()
+}
+
+fn measure(size: usize, forward: bool, iterations: usize, warmup: usize) -> Measurement {
+    let mut fft = fft::create_fft_f32(size);
+    let mut input = random_input(size);
+
+    for _ in 0..warmup {
+        if forward {
+            fft.fft_in_place(&mut input);
+        } else {
+            fft.ifft_in_place(&mut input);
+        }
+    }
+
+    let mut timings = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        if forward {
+            fft.fft_in_place(&mut input);
+        } else {
+            fft.ifft_in_place(&mut input);
+        }
+        timings.push(start.elapsed());
+    }
+    timings.sort();
+
+    Measurement {
+        size,
+        min: timings[0],
+        median: timings[timings.len() / 2],
+    }
+}
+
+fn main() {
+    let matches = App::new("fft-prof")
+        .arg(
+            Arg::with_name("sizes")
+                .takes_value(true)
+                .required(true)
+                .help("comma-separated sizes, or a `start..end` range"),
+        )
+        .arg(
+            Arg::with_name("inverse")
+                .long("inverse")
+                .help("times the inverse transform instead of the forward transform"),
+        )
+        .arg(
+            Arg::with_name("iterations")
+                .long("iterations")
+                .takes_value(true)
+                .default_value("100")
+                .help("number of timed runs per size"),
+        )
+        .arg(
+            Arg::with_name("warmup")
+                .long("warmup")
+                .takes_value(true)
+                .default_value("10")
+                .help("number of untimed runs per size, before the timed runs"),
+        )
+        .arg(
+            Arg::with_name("power-of-two-only")
+                .long("power-of-two-only")
+                .help("skip sizes that aren't a power of two"),
+        )
+        .arg(
+            Arg::with_name("prime-only")
+                .long("prime-only")
+                .help("skip sizes that aren't prime (primes above 7 take the Rader path)"),
+        )
+        .arg(Arg::with_name("csv").long("csv").help("print results as CSV instead of a table"))
+        .get_matches();
+
+    let forward = !matches.is_present("inverse");
+    let iterations: usize = matches.value_of("iterations").unwrap().parse().expect("iterations must be a number");
+    let warmup: usize = matches.value_of("warmup").unwrap().parse().expect("warmup must be a number");
+    let csv = matches.is_present("csv");
+    let power_of_two_only = matches.is_present("power-of-two-only");
+    let prime_only = matches.is_present("prime-only");
+
+    let sizes: Vec<usize> = parse_sizes(matches.value_of("sizes").unwrap())
+        .into_iter()
+        .filter(|&size| !power_of_two_only || is_power_of_two_friendly(size))
+        .filter(|&size| {
+            !prime_only || (size >= 2 && (2..size).take_while(|d| d * d <= size).all(|d| size % d != 0))
+        })
+        .collect();
+
+    if csv {
+        println!("size,min_ns,median_ns,min_gflops,median_gflops");
+    } else {
+        println!(
+            "{:>10} {:>12} {:>12} {:>12} {:>12}",
+            "size", "min", "median", "min GFLOP/s", "median GFLOP/s"
+        );
+    }
 
-    loop {
-        fft.fft_in_place(&mut input);
+    for size in sizes {
+        let m = measure(size, forward, iterations, warmup);
+        let min_gflops = m.gflops(m.min);
+        let median_gflops = m.gflops(m.median);
+        if csv {
+            println!(
+                "{},{},{},{:.3},{:.3}",
+                m.size,
+                m.min.as_nanos(),
+                m.median.as_nanos(),
+                min_gflops,
+                median_gflops
+            );
+        } else {
+            println!(
+                "{:>10} {:>12?} {:>12?} {:>12.3} {:>12.3}",
+                m.size, m.min, m.median, min_gflops, median_gflops
+            );
+        }
     }
 }
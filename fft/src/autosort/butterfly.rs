@@ -32,3 +32,45 @@ macro_rules! butterfly4 {
         }
     }
 }
+
+#[macro_export]
+macro_rules! butterfly7 {
+    { $input:tt, $forward:tt } => {
+        {
+            let w1 = broadcast!(crate::twiddle::compute_twiddle(1, 7, $forward));
+            let w2 = broadcast!(crate::twiddle::compute_twiddle(2, 7, $forward));
+            let w3 = broadcast!(crate::twiddle::compute_twiddle(3, 7, $forward));
+            let w4 = broadcast!(crate::twiddle::compute_twiddle(4, 7, $forward));
+            let w5 = broadcast!(crate::twiddle::compute_twiddle(5, 7, $forward));
+            let w6 = broadcast!(crate::twiddle::compute_twiddle(6, 7, $forward));
+            [
+                add!($input[0], add!($input[1], add!($input[2], add!($input[3], add!($input[4], add!($input[5], $input[6])))))),
+                add!($input[0], add!(mul!($input[1], w1), add!(mul!($input[2], w2), add!(mul!($input[3], w3), add!(mul!($input[4], w4), add!(mul!($input[5], w5), mul!($input[6], w6))))))),
+                add!($input[0], add!(mul!($input[1], w2), add!(mul!($input[2], w4), add!(mul!($input[3], w6), add!(mul!($input[4], w1), add!(mul!($input[5], w3), mul!($input[6], w5))))))),
+                add!($input[0], add!(mul!($input[1], w3), add!(mul!($input[2], w6), add!(mul!($input[3], w2), add!(mul!($input[4], w5), add!(mul!($input[5], w1), mul!($input[6], w4))))))),
+                add!($input[0], add!(mul!($input[1], w4), add!(mul!($input[2], w1), add!(mul!($input[3], w5), add!(mul!($input[4], w2), add!(mul!($input[5], w6), mul!($input[6], w3))))))),
+                add!($input[0], add!(mul!($input[1], w5), add!(mul!($input[2], w3), add!(mul!($input[3], w1), add!(mul!($input[4], w6), add!(mul!($input[5], w4), mul!($input[6], w2))))))),
+                add!($input[0], add!(mul!($input[1], w6), add!(mul!($input[2], w5), add!(mul!($input[3], w4), add!(mul!($input[4], w3), add!(mul!($input[5], w2), mul!($input[6], w1))))))),
+            ]
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! butterfly5 {
+    { $input:tt, $forward:tt } => {
+        {
+            let w1 = broadcast!(crate::twiddle::compute_twiddle(1, 5, $forward));
+            let w2 = broadcast!(crate::twiddle::compute_twiddle(2, 5, $forward));
+            let w3 = broadcast!(crate::twiddle::compute_twiddle(3, 5, $forward));
+            let w4 = broadcast!(crate::twiddle::compute_twiddle(4, 5, $forward));
+            [
+                add!($input[0], add!($input[1], add!($input[2], add!($input[3], $input[4])))),
+                add!($input[0], add!(mul!($input[1], w1), add!(mul!($input[2], w2), add!(mul!($input[3], w3), mul!($input[4], w4))))),
+                add!($input[0], add!(mul!($input[1], w2), add!(mul!($input[2], w4), add!(mul!($input[3], w1), mul!($input[4], w3))))),
+                add!($input[0], add!(mul!($input[1], w3), add!(mul!($input[2], w1), add!(mul!($input[3], w4), mul!($input[4], w2))))),
+                add!($input[0], add!(mul!($input[1], w4), add!(mul!($input[2], w3), add!(mul!($input[3], w2), mul!($input[4], w1))))),
+            ]
+        }
+    }
+}
@@ -7,6 +7,8 @@ mod radix2;
 mod radix3;
 mod radix4;
 mod radix8;
+mod radix16;
+pub(crate) mod radixn;
 use crate::vector::ComplexVector;
 
 #[inline(always)]
@@ -60,6 +60,78 @@ unsafe fn in_place_impl<
                 { !DATA_IN_WORK },
             >(input, work);
         }
+    } else if SIZE % 3 == 0 {
+        let butterfly = crate::autosort::radixn::RadixN::<T, 3>::new(FORWARD);
+        let twiddles = make_twiddles::<T, 3, { SIZE }, { FORWARD }>();
+        if STRIDE >= Vector::WIDTH {
+            butterfly.apply_step_full::<Vector>(from, to, SIZE, STRIDE, &twiddles);
+        } else {
+            butterfly.apply_step_partial::<Vector>(from, to, SIZE, STRIDE, &twiddles);
+        }
+        if SIZE != 3 {
+            return in_place_impl::<
+                T,
+                Vector,
+                FORWARD,
+                { SIZE / 3 },
+                {
+                    if STRIDE <= (std::usize::MAX / 3) {
+                        STRIDE * 3
+                    } else {
+                        STRIDE
+                    }
+                },
+                { !DATA_IN_WORK },
+            >(input, work);
+        }
+    } else if SIZE % 5 == 0 {
+        let butterfly = crate::autosort::radixn::RadixN::<T, 5>::new(FORWARD);
+        let twiddles = make_twiddles::<T, 5, { SIZE }, { FORWARD }>();
+        if STRIDE >= Vector::WIDTH {
+            butterfly.apply_step_full::<Vector>(from, to, SIZE, STRIDE, &twiddles);
+        } else {
+            butterfly.apply_step_partial::<Vector>(from, to, SIZE, STRIDE, &twiddles);
+        }
+        if SIZE != 5 {
+            return in_place_impl::<
+                T,
+                Vector,
+                FORWARD,
+                { SIZE / 5 },
+                {
+                    if STRIDE <= (std::usize::MAX / 5) {
+                        STRIDE * 5
+                    } else {
+                        STRIDE
+                    }
+                },
+                { !DATA_IN_WORK },
+            >(input, work);
+        }
+    } else if SIZE % 7 == 0 {
+        let butterfly = crate::autosort::radixn::RadixN::<T, 7>::new(FORWARD);
+        let twiddles = make_twiddles::<T, 7, { SIZE }, { FORWARD }>();
+        if STRIDE >= Vector::WIDTH {
+            butterfly.apply_step_full::<Vector>(from, to, SIZE, STRIDE, &twiddles);
+        } else {
+            butterfly.apply_step_partial::<Vector>(from, to, SIZE, STRIDE, &twiddles);
+        }
+        if SIZE != 7 {
+            return in_place_impl::<
+                T,
+                Vector,
+                FORWARD,
+                { SIZE / 7 },
+                {
+                    if STRIDE <= (std::usize::MAX / 7) {
+                        STRIDE * 7
+                    } else {
+                        STRIDE
+                    }
+                },
+                { !DATA_IN_WORK },
+            >(input, work);
+        }
     } else if SIZE % 2 == 0 {
         let butterfly = crate::autosort::radix2::Radix2 {};
         let twiddles = make_twiddles::<T, 2, { SIZE }, { FORWARD }>();
@@ -144,6 +216,10 @@ impl PowerTwoFft32 {
 impl Fft for PowerTwoFft32 {
     type Float = f32;
 
+    fn size(&self) -> usize {
+        self.size
+    }
+
     fn fft_in_place(&mut self, input: &mut [Complex<f32>]) {
         assert_eq!(input.len(), self.size, "input must match configured size");
         in_place_f32_dispatch::<true, { MAX_POW_2 }>(input, &mut self.work);
@@ -153,4 +229,23 @@ impl Fft for PowerTwoFft32 {
         assert_eq!(input.len(), self.size, "input must match configured size");
         in_place_f32_dispatch::<false, { MAX_POW_2 }>(input, &mut self.work);
     }
+
+    fn scratch_len(&self) -> usize {
+        self.size
+    }
+
+    fn process_with_scratch(
+        &self,
+        buffer: &mut [Complex<f32>],
+        scratch: &mut [Complex<f32>],
+        forward: bool,
+    ) {
+        assert_eq!(buffer.len(), self.size, "buffer must match configured size");
+        assert!(scratch.len() >= self.size, "scratch must hold at least size() elements");
+        if forward {
+            in_place_f32_dispatch::<true, { MAX_POW_2 }>(buffer, scratch);
+        } else {
+            in_place_f32_dispatch::<false, { MAX_POW_2 }>(buffer, scratch);
+        }
+    }
 }
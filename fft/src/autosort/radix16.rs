@@ -0,0 +1,164 @@
+use super::{radix2, radix8, BaseConfig};
+use crate::float::FftFloat;
+use crate::{avx, generic};
+use num_complex::Complex;
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+pub struct Radix16<T> {
+    base: BaseConfig<T>,
+    /// The seven non-trivial size-16 twiddles `e^{-i*pi*k/8}` for `k = 1..=7`,
+    /// precomputed once the way [`radix8::Radix8`] precomputes its single twiddle.
+    twiddles: [Complex<T>; 7],
+}
+
+impl<T: FftFloat> Radix16<T> {
+    pub fn new(size: usize, stride: usize, forward: bool) -> Self {
+        let mut twiddles = [Complex::default(); 7];
+        for (k, twiddle) in twiddles.iter_mut().enumerate() {
+            *twiddle = super::compute_twiddle(k + 1, 16, forward);
+        }
+        Self {
+            base: BaseConfig::new(size, stride, 16, forward),
+            twiddles,
+        }
+    }
+
+    pub fn forward(size: usize, stride: usize) -> Self {
+        Self::new(size, stride, true)
+    }
+
+    pub fn inverse(size: usize, stride: usize) -> Self {
+        Self::new(size, stride, false)
+    }
+}
+
+macro_rules! make_butterfly {
+    {
+        $input:ident,
+        $forward:ident,
+        $twiddles:ident,
+        $bfly2:path,
+        $bfly8:path,
+        $mul:path,
+    } => {
+        {
+            let x = $input;
+            let forward = $forward;
+            let twiddles = $twiddles;
+            let a1 = $bfly8([x[0], x[2], x[4], x[6], x[8], x[10], x[12], x[14]], forward);
+            let mut b1 = $bfly8([x[1], x[3], x[5], x[7], x[9], x[11], x[13], x[15]], forward);
+            for k in 1..8 {
+                b1[k] = $mul(b1[k], twiddles[k - 1]);
+            }
+            let c0 = $bfly2([a1[0], b1[0]], forward);
+            let c1 = $bfly2([a1[1], b1[1]], forward);
+            let c2 = $bfly2([a1[2], b1[2]], forward);
+            let c3 = $bfly2([a1[3], b1[3]], forward);
+            let c4 = $bfly2([a1[4], b1[4]], forward);
+            let c5 = $bfly2([a1[5], b1[5]], forward);
+            let c6 = $bfly2([a1[6], b1[6]], forward);
+            let c7 = $bfly2([a1[7], b1[7]], forward);
+            [
+                c0[0], c1[0], c2[0], c3[0], c4[0], c5[0], c6[0], c7[0],
+                c0[1], c1[1], c2[1], c3[1], c4[1], c5[1], c6[1], c7[1],
+            ]
+        }
+    }
+}
+
+#[inline(always)]
+pub fn butterfly<T: FftFloat>(
+    x: [Complex<T>; 16],
+    forward: bool,
+    twiddles: [Complex<T>; 7],
+) -> [Complex<T>; 16] {
+    make_butterfly! {
+        x,
+        forward,
+        twiddles,
+        radix2::butterfly,
+        radix8::butterfly,
+        generic::mul,
+    }
+}
+
+#[multiversion::target_clones("[x86|x86_64]+avx", "[x86|x86_64]+avx+fma")]
+#[inline]
+pub unsafe fn butterfly_avx(
+    x: [__m256; 16],
+    forward: bool,
+    twiddle: __m256,
+    twiddle_neg: __m256,
+    twiddles: [__m256; 7],
+) -> [__m256; 16] {
+    #[static_dispatch]
+    use avx::mul;
+    let bfly8 = move |x: [__m256; 8], forward: bool| -> [__m256; 8] {
+        radix8::butterfly_avx(x, forward, twiddle, twiddle_neg)
+    };
+    make_butterfly! {
+        x,
+        forward,
+        twiddles,
+        radix2::butterfly_avx,
+        bfly8,
+        mul,
+    }
+}
+
+#[inline]
+pub fn radix16<T: FftFloat>(x: &[Complex<T>], y: &mut [Complex<T>], config: &Radix16<T>) {
+    let twiddles = config.twiddles;
+
+    let bfly = move |x: [Complex<T>; 16], forward: bool| -> [Complex<T>; 16] {
+        butterfly(x, forward, twiddles)
+    };
+
+    crate::implement_generic! {16, x, y, &config.base, bfly}
+}
+
+#[multiversion::target("[x86|x86_64]+avx")]
+unsafe fn radix16_f32_avx(x: &[Complex<f32>], y: &mut [Complex<f32>], config: &Radix16<f32>) {
+    #[static_dispatch]
+    use crate::avx::mul;
+    #[static_dispatch]
+    use butterfly_avx;
+
+    // The radix-8 half of the butterfly needs its own eighth-turn twiddle
+    // (`compute_twiddle(1, 8)`), the same pair `radix8_f32_avx` builds from
+    // `config.twiddle`/`twiddle_neg`; reuse the size-16 twiddle for `k = 2`
+    // (the `e^{-i*pi/4}` term of this size-16 transform).
+    let inner_twiddle = config.twiddles[1];
+    let twiddle = _mm256_blend_ps(
+        _mm256_set1_ps(inner_twiddle.re),
+        _mm256_set1_ps(inner_twiddle.im),
+        0xaa,
+    );
+    let twiddle_neg = _mm256_blend_ps(
+        _mm256_set1_ps(-inner_twiddle.re),
+        _mm256_set1_ps(inner_twiddle.im),
+        0xaa,
+    );
+
+    let mut twiddles = [_mm256_setzero_ps(); 7];
+    for (k, t) in config.twiddles.iter().enumerate() {
+        twiddles[k] = _mm256_blend_ps(_mm256_set1_ps(t.re), _mm256_set1_ps(t.im), 0xaa);
+    }
+
+    let bfly = move |x: [__m256; 16], forward: bool| -> [__m256; 16] {
+        butterfly_avx(x, forward, twiddle, twiddle_neg, twiddles)
+    };
+
+    crate::implement_avx_f32! {16, x, y, &config.base, bfly}
+}
+
+#[multiversion::multiversion(
+    "[x86|x86_64]+avx" => radix16_f32_avx
+)]
+pub fn radix16_f32(x: &[Complex<f32>], y: &mut [Complex<f32>], config: &Radix16<f32>) {
+    radix16(x, y, config);
+}
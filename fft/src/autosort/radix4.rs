@@ -1,7 +1,7 @@
 use super::radix2::Radix2;
 use super::Butterfly;
 use crate::float::FftFloat;
-use crate::vector::ComplexVector;
+use crate::vector::{ComplexVector, CyclicAlgebra};
 
 pub struct Radix4 {
     radix2: Radix2,
@@ -26,7 +26,10 @@ impl<T: FftFloat> Butterfly<T, 4> for Radix4 {
     unsafe fn apply<Vector: ComplexVector<Float = T>>(&self, x: [Vector; 4]) -> [Vector; 4] {
         let a1 = self.radix2.apply([x[0], x[2]]);
         let mut b1 = self.radix2.apply([x[1], x[3]]);
-        b1[1] = b1[1].rotate(self.forward);
+        // Goes through `rotate_or_twiddle` rather than `rotate` directly. Every
+        // `ComplexVector` has a fast rotate, so the twiddle fallback is never
+        // called; it is `unreachable!` and supplies no actual `±i` twiddle.
+        b1[1] = b1[1].rotate_or_twiddle(self.forward, || unreachable!("ComplexVector always has a fast rotate"));
         let a2 = self.radix2.apply([a1[0], b1[0]]);
         let b2 = self.radix2.apply([a1[1], b1[1]]);
         [a2[0], b2[1], a2[1], b2[0]]
@@ -0,0 +1,48 @@
+use super::Butterfly;
+use crate::float::FftFloat;
+use crate::twiddle::compute_twiddle;
+use crate::vector::ComplexVector;
+use num_complex::Complex;
+
+/// A generic cross-FFT butterfly for any radix in `2..=7`, computed as a direct
+/// size-`RADIX` DFT rather than a hand-unrolled decomposition.
+///
+/// Unlike [`radix2::Radix2`](super::radix2::Radix2), [`radix3::Radix3`](super::radix3::Radix3),
+/// and [`radix4::Radix4`](super::radix4::Radix4), which each hard-code their own cross-FFT,
+/// a single `RadixN<T, RADIX>` instance serves whichever radix it is instantiated with, so
+/// a mixed-radix planner can select it for any factor without a distinct struct per radix.
+pub struct RadixN<T, const RADIX: usize> {
+    /// Row-major `RADIX x RADIX` table: `twiddles[j * RADIX + k] == w_RADIX^{jk}`.
+    twiddles: Box<[Complex<T>]>,
+}
+
+impl<T: FftFloat, const RADIX: usize> Butterfly<T, RADIX> for RadixN<T, RADIX> {
+    fn new(forward: bool) -> Self {
+        let mut twiddles = Vec::with_capacity(RADIX * RADIX);
+        for j in 0..RADIX {
+            for k in 0..RADIX {
+                twiddles.push(compute_twiddle(j * k, RADIX, forward));
+            }
+        }
+        Self {
+            twiddles: twiddles.into_boxed_slice(),
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn apply<Vector: ComplexVector<Float = T>>(
+        &self,
+        x: [Vector; RADIX],
+    ) -> [Vector; RADIX] {
+        let mut out = super::zeroed_array::<T, Vector, RADIX>();
+        for j in 0..RADIX {
+            let mut acc = x[0];
+            for k in 1..RADIX {
+                let w = unsafe { Vector::broadcast(&self.twiddles[j * RADIX + k]) };
+                acc = unsafe { acc.add(&x[k].mul(&w)) };
+            }
+            out[j] = acc;
+        }
+        out
+    }
+}
@@ -22,6 +22,17 @@ pub unsafe fn mul(a: __m256, b: __m256) -> __m256 {
     _mm256_addsub_ps(_mm256_mul_ps(a_re, b), _mm256_mul_ps(a_im, b_sh))
 }
 
+// Same complex product as `mul`, but fuses the second multiply-subtract into
+// a single `vfmaddsub213ps`, cutting one op off the dependent chain.
+#[multiversion::target("[x86|x86_64]+avx+fma")]
+#[inline]
+pub unsafe fn mul(a: __m256, b: __m256) -> __m256 {
+    let a_re = _mm256_moveldup_ps(a);
+    let a_im = _mm256_movehdup_ps(a);
+    let b_sh = _mm256_permute_ps(b, 0xb1);
+    _mm256_fmaddsub_ps(a_re, b, _mm256_mul_ps(a_im, b_sh))
+}
+
 #[multiversion::target("[x86|x86_64]+avx")]
 #[inline]
 pub unsafe fn partial_mask(count: usize) -> __m256i {
@@ -31,3 +42,39 @@ pub unsafe fn partial_mask(count: usize) -> __m256i {
     let has_3 = if count >= 3 { -1 } else { 0 };
     _mm256_set_epi32(0, 0, has_3, has_3, has_2, has_2, -1, -1)
 }
+
+#[multiversion::target("[x86|x86_64]+avx")]
+#[inline]
+pub unsafe fn rotate_pd(z: __m256d, forward: bool) -> __m256d {
+    if forward {
+        _mm256_addsub_pd(_mm256_setzero_pd(), _mm256_permute_pd(z, 0x5))
+    } else {
+        _mm256_permute_pd(_mm256_addsub_pd(_mm256_setzero_pd(), z), 0x5)
+    }
+}
+
+#[multiversion::target("[x86|x86_64]+avx")]
+#[inline]
+pub unsafe fn mul_pd(a: __m256d, b: __m256d) -> __m256d {
+    let a_re = _mm256_unpacklo_pd(a, a);
+    let a_im = _mm256_unpackhi_pd(a, a);
+    let b_sh = _mm256_permute_pd(b, 0x5);
+    _mm256_addsub_pd(_mm256_mul_pd(a_re, b), _mm256_mul_pd(a_im, b_sh))
+}
+
+// f64 sibling of the FMA `mul` above.
+#[multiversion::target("[x86|x86_64]+avx+fma")]
+#[inline]
+pub unsafe fn mul_pd(a: __m256d, b: __m256d) -> __m256d {
+    let a_re = _mm256_unpacklo_pd(a, a);
+    let a_im = _mm256_unpackhi_pd(a, a);
+    let b_sh = _mm256_permute_pd(b, 0x5);
+    _mm256_fmaddsub_pd(a_re, b, _mm256_mul_pd(a_im, b_sh))
+}
+
+#[multiversion::target("[x86|x86_64]+avx")]
+#[inline]
+pub unsafe fn partial_mask_pd(count: usize) -> __m256i {
+    assert_eq!(count, 1);
+    _mm256_set_epi64x(0, 0, -1, -1)
+}
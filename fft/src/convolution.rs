@@ -0,0 +1,311 @@
+use crate::autosort::prime_factor::PrimeFactorFft32;
+use crate::fft::Fft;
+use num_complex::Complex;
+
+/// The smallest `n >= min` whose only prime factors are 2 and 3 -- sizes the
+/// prime-factor autosort planner (see [`PrimeFactorFft32`]) handles with its
+/// radix-2/3/4 stages.
+fn smooth_size(min: usize) -> usize {
+    let mut n = min.max(1);
+    loop {
+        let mut m = n;
+        while m % 2 == 0 {
+            m /= 2;
+        }
+        while m % 3 == 0 {
+            m /= 3;
+        }
+        if m == 1 {
+            return n;
+        }
+        n += 1;
+    }
+}
+
+/// Plans FFT-based convolution and correlation, caching the inner transform
+/// so repeated calls with same-shaped operands avoid replanning.
+pub struct ConvolutionPlanner {
+    size: usize,
+    fft: PrimeFactorFft32,
+}
+
+impl ConvolutionPlanner {
+    /// Creates a planner able to convolve operands whose padded transform
+    /// size is at least `min_size`.
+    pub fn new(min_size: usize) -> Self {
+        let size = smooth_size(min_size);
+        Self {
+            size,
+            fft: PrimeFactorFft32::new(size),
+        }
+    }
+
+    fn ensure_size(&mut self, min_size: usize) {
+        let size = smooth_size(min_size);
+        if size != self.size {
+            self.size = size;
+            self.fft = PrimeFactorFft32::new(size);
+        }
+    }
+
+    fn forward_pad(
+        &mut self,
+        a: &[Complex<f32>],
+        b: &[Complex<f32>],
+        min_size: usize,
+    ) -> (Vec<Complex<f32>>, Vec<Complex<f32>>) {
+        self.ensure_size(min_size);
+        let mut x = vec![Complex::default(); self.size];
+        let mut y = vec![Complex::default(); self.size];
+        x[..a.len()].copy_from_slice(a);
+        y[..b.len()].copy_from_slice(b);
+        self.fft.fft_in_place(&mut x);
+        self.fft.fft_in_place(&mut y);
+        (x, y)
+    }
+
+    /// Computes the linear convolution of `a` and `b`, of length
+    /// `a.len() + b.len() - 1`.
+    pub fn linear_convolve(&mut self, a: &[Complex<f32>], b: &[Complex<f32>]) -> Vec<Complex<f32>> {
+        let result_len = a.len() + b.len() - 1;
+        let (mut x, y) = self.forward_pad(a, b, result_len);
+        for (x, y) in x.iter_mut().zip(y.iter()) {
+            *x *= y;
+        }
+        self.fft.ifft_in_place(&mut x);
+        x.truncate(result_len);
+        x
+    }
+
+    /// Computes the circular convolution of `a` and `b`, which must have
+    /// equal length.
+    pub fn circular_convolve(&mut self, a: &[Complex<f32>], b: &[Complex<f32>]) -> Vec<Complex<f32>> {
+        assert_eq!(
+            a.len(),
+            b.len(),
+            "circular convolution requires equal-length inputs"
+        );
+        let n = a.len();
+        if n == 0 {
+            return Vec::new();
+        }
+        // The padded size may exceed `n`, so compute the linear convolution and
+        // fold it back modulo `n`.
+        let (mut x, y) = self.forward_pad(a, b, 2 * n - 1);
+        for (x, y) in x.iter_mut().zip(y.iter()) {
+            *x *= y;
+        }
+        self.fft.ifft_in_place(&mut x);
+        let mut result = vec![Complex::default(); n];
+        for (k, value) in x.iter().take(2 * n - 1).enumerate() {
+            result[k % n] += value;
+        }
+        result
+    }
+
+    /// Computes the cross-correlation of `a` and `b`, by conjugating `b`'s
+    /// spectrum before the pointwise product instead of reversing `b` in the
+    /// time domain. Lag `k` (from `-(b.len() - 1)` to `a.len() - 1`) is at
+    /// index `k + b.len() - 1` of the result.
+    pub fn cross_correlate(&mut self, a: &[Complex<f32>], b: &[Complex<f32>]) -> Vec<Complex<f32>> {
+        let result_len = a.len() + b.len() - 1;
+        let (mut x, y) = self.forward_pad(a, b, result_len);
+        for (x, y) in x.iter_mut().zip(y.iter()) {
+            *x *= y.conj();
+        }
+        self.fft.ifft_in_place(&mut x);
+        // Negative lags wrap around to the end of the padded buffer.
+        let shift = b.len() - 1;
+        (0..result_len)
+            .map(|j| x[(j + self.size - shift) % self.size])
+            .collect()
+    }
+}
+
+/// Computes the linear convolution of `a` and `b`, of length
+/// `a.len() + b.len() - 1`.
+pub fn linear_convolve(a: &[Complex<f32>], b: &[Complex<f32>]) -> Vec<Complex<f32>> {
+    ConvolutionPlanner::new(a.len() + b.len() - 1).linear_convolve(a, b)
+}
+
+/// Computes the circular convolution of `a` and `b`, which must have equal
+/// length.
+pub fn circular_convolve(a: &[Complex<f32>], b: &[Complex<f32>]) -> Vec<Complex<f32>> {
+    ConvolutionPlanner::new(a.len()).circular_convolve(a, b)
+}
+
+/// Computes the cross-correlation of `a` and `b`.
+pub fn cross_correlate(a: &[Complex<f32>], b: &[Complex<f32>]) -> Vec<Complex<f32>> {
+    ConvolutionPlanner::new(a.len() + b.len() - 1).cross_correlate(a, b)
+}
+
+/// When the shorter operand is below this length, [`convolve`] uses the
+/// recursive Karatsuba multiply instead of routing through an FFT, since the
+/// transform's fixed overhead dominates at small sizes.
+const KARATSUBA_THRESHOLD: usize = 64;
+
+/// Below this length, [`karatsuba_convolve`] stops recursing and uses
+/// [`direct_convolve`].
+const SCHOOLBOOK_THRESHOLD: usize = 32;
+
+/// Direct schoolbook convolution: the base case [`karatsuba_convolve`]
+/// bottoms out at, and the fallback for mismatched operand lengths.
+fn direct_convolve(a: &[Complex<f32>], b: &[Complex<f32>]) -> Vec<Complex<f32>> {
+    let mut result = vec![Complex::default(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            result[i + j] += x * y;
+        }
+    }
+    result
+}
+
+/// Recursive Karatsuba multiply for equal-length operands: splits each into
+/// low/high halves of size `n / 2`, computes `z0 = lo_a*lo_b`,
+/// `z2 = hi_a*hi_b`, and `z1 = (lo_a+hi_a)*(lo_b+hi_b) - z0 - z2`, then
+/// combines the three with the appropriate shifts. Recurses until below
+/// [`SCHOOLBOOK_THRESHOLD`], where it bottoms out in [`direct_convolve`].
+/// Mismatched lengths also fall back to [`direct_convolve`] directly.
+fn karatsuba_convolve(a: &[Complex<f32>], b: &[Complex<f32>]) -> Vec<Complex<f32>> {
+    if a.len() != b.len() || a.len() < SCHOOLBOOK_THRESHOLD {
+        return direct_convolve(a, b);
+    }
+
+    let n = a.len();
+    let split = n / 2;
+    let (a_lo, a_hi) = a.split_at(split);
+    let (b_lo, b_hi) = b.split_at(split);
+
+    let z0 = karatsuba_convolve(a_lo, b_lo);
+    let z2 = karatsuba_convolve(a_hi, b_hi);
+
+    let mut a_sum = a_lo.to_vec();
+    a_sum.resize(a_hi.len().max(a_lo.len()), Complex::default());
+    for (s, x) in a_sum.iter_mut().zip(a_hi.iter()) {
+        *s += x;
+    }
+    let mut b_sum = b_lo.to_vec();
+    b_sum.resize(b_hi.len().max(b_lo.len()), Complex::default());
+    for (s, x) in b_sum.iter_mut().zip(b_hi.iter()) {
+        *s += x;
+    }
+    let z1 = karatsuba_convolve(&a_sum, &b_sum);
+
+    let mut result = vec![Complex::default(); 2 * n - 1];
+    for (i, v) in z0.iter().enumerate() {
+        result[i] += v;
+        result[split + i] -= v;
+    }
+    for (i, v) in z2.iter().enumerate() {
+        result[split + i] -= v;
+        result[2 * split + i] += v;
+    }
+    for (i, v) in z1.iter().enumerate() {
+        result[split + i] += v;
+    }
+    result
+}
+
+/// Computes the linear convolution of `a` and `b`, of length
+/// `a.len() + b.len() - 1`. Below [`KARATSUBA_THRESHOLD`], uses the
+/// recursive Karatsuba multiply ([`karatsuba_convolve`]) instead of an FFT
+/// round-trip, since the fixed transform overhead dominates at small sizes.
+pub fn convolve(a: &[Complex<f32>], b: &[Complex<f32>]) -> Vec<Complex<f32>> {
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        karatsuba_convolve(a, b)
+    } else {
+        linear_convolve(a, b)
+    }
+}
+
+/// Real-input counterpart to [`convolve`]: the imaginary part of a
+/// real-times-real product is exactly zero, so only the real part of the
+/// result is returned.
+pub fn convolve_real(a: &[f32], b: &[f32]) -> Vec<f32> {
+    let a: Vec<Complex<f32>> = a.iter().map(|&x| Complex::new(x, 0.0)).collect();
+    let b: Vec<Complex<f32>> = b.iter().map(|&x| Complex::new(x, 0.0)).collect();
+    convolve(&a, &b).iter().map(|c| c.re).collect()
+}
+
+/// Filters a streaming signal against a fixed, short FIR `kernel` with the
+/// overlap-add method: the kernel's spectrum is transformed once up front, and each
+/// call to [`process`](OverlapAdd::process) convolves one more block of input against
+/// it, carrying the trailing overlap into the next call instead of recomputing the
+/// kernel's FFT or buffering the whole signal. This is the usual way to apply an FFT
+/// to signals far longer than fit comfortably in one transform.
+pub struct OverlapAdd {
+    fft: PrimeFactorFft32,
+    size: usize,
+    kernel_len: usize,
+    block_len: usize,
+    kernel_spectrum: Vec<Complex<f32>>,
+    overlap: Vec<Complex<f32>>,
+}
+
+impl OverlapAdd {
+    /// Creates an overlap-add filter for the given FIR `kernel`.
+    pub fn new(kernel: &[Complex<f32>]) -> Self {
+        let kernel_len = kernel.len();
+        assert!(kernel_len > 0, "kernel must not be empty");
+        let size = smooth_size((kernel_len * 4).max(kernel_len + 1));
+        let block_len = size - kernel_len + 1;
+
+        let mut fft = PrimeFactorFft32::new(size);
+        let mut kernel_spectrum = vec![Complex::default(); size];
+        kernel_spectrum[..kernel_len].copy_from_slice(kernel);
+        fft.fft_in_place(&mut kernel_spectrum);
+
+        Self {
+            fft,
+            size,
+            kernel_len,
+            block_len,
+            kernel_spectrum,
+            overlap: vec![Complex::default(); kernel_len - 1],
+        }
+    }
+
+    /// The number of input samples each internal block holds. Feeding `input` to
+    /// [`process`](OverlapAdd::process) in multiples of this size isn't required,
+    /// but avoids splitting a block across two calls' worth of internal padding.
+    pub fn block_len(&self) -> usize {
+        self.block_len
+    }
+
+    /// Filters `input`, appending exactly `input.len()` output samples to `output`.
+    /// `input` is internally split into `block_len()`-sized blocks (the last one
+    /// zero-padded if short), convolved against the cached kernel spectrum, and
+    /// combined with the overlap carried from the previous call. Call
+    /// [`flush`](OverlapAdd::flush) once the stream ends to emit the trailing
+    /// `kernel.len() - 1` samples still held in the overlap buffer.
+    pub fn process(&mut self, input: &[Complex<f32>], output: &mut Vec<Complex<f32>>) {
+        for block in input.chunks(self.block_len) {
+            let mut x = vec![Complex::default(); self.size];
+            x[..block.len()].copy_from_slice(block);
+            self.fft.fft_in_place(&mut x);
+            for (x, k) in x.iter_mut().zip(self.kernel_spectrum.iter()) {
+                *x *= k;
+            }
+            self.fft.ifft_in_place(&mut x);
+
+            for (x, overlap) in x.iter_mut().zip(self.overlap.iter()) {
+                *x += overlap;
+            }
+            output.extend_from_slice(&x[..block.len()]);
+
+            let tail_len = self.kernel_len - 1;
+            self.overlap.clear();
+            self.overlap.extend_from_slice(&x[block.len()..block.len() + tail_len]);
+        }
+    }
+
+    /// Emits the trailing `kernel.len() - 1` samples still held in the overlap
+    /// buffer and resets it to zero. Call once after the last
+    /// [`process`](OverlapAdd::process) call for a stream.
+    pub fn flush(&mut self, output: &mut Vec<Complex<f32>>) {
+        output.extend_from_slice(&self.overlap);
+        for x in self.overlap.iter_mut() {
+            *x = Complex::default();
+        }
+    }
+}
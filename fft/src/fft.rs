@@ -3,6 +3,67 @@ use num_complex::Complex;
 pub trait Fft {
     type Float;
 
+    /// The configured transform size: the length `fft_in_place`/
+    /// `ifft_in_place` expect, and the segment length `*_batch` divides
+    /// `data` into.
+    fn size(&self) -> usize;
+
     fn fft_in_place(&mut self, input: &mut [Complex<Self::Float>]);
     fn ifft_in_place(&mut self, input: &mut [Complex<Self::Float>]);
+
+    /// The scratch space (in elements) [`process_with_scratch`](Fft::process_with_scratch)
+    /// and [`process_out_of_place`](Fft::process_out_of_place) require. Defaults to
+    /// `size()`.
+    fn scratch_len(&self) -> usize {
+        self.size()
+    }
+
+    /// Transforms `buffer` in place using caller-owned `scratch` (at least
+    /// `scratch_len()` elements) instead of any scratch space owned by `self`. Unlike
+    /// [`fft_in_place`](Fft::fft_in_place)/[`ifft_in_place`](Fft::ifft_in_place), this
+    /// only needs `&self`, so one planner can serve several concurrent transforms, each
+    /// with its own caller-supplied scratch.
+    fn process_with_scratch(
+        &self,
+        buffer: &mut [Complex<Self::Float>],
+        scratch: &mut [Complex<Self::Float>],
+        forward: bool,
+    );
+
+    /// Out-of-place counterpart to [`process_with_scratch`](Fft::process_with_scratch):
+    /// copies `input` into `output` and transforms it there, leaving `input` untouched.
+    fn process_out_of_place(
+        &self,
+        input: &[Complex<Self::Float>],
+        output: &mut [Complex<Self::Float>],
+        scratch: &mut [Complex<Self::Float>],
+        forward: bool,
+    ) where
+        Self::Float: Clone,
+    {
+        output.clone_from_slice(input);
+        self.process_with_scratch(output, scratch, forward);
+    }
+
+    /// Transforms each `size()`-length segment of `data` in place. `data`
+    /// must hold a whole number of segments. Reuses the same scratch
+    /// allocation across the whole batch, since each segment is transformed
+    /// through the same `&mut self` rather than a freshly planned FFT.
+    fn fft_in_place_batch(&mut self, data: &mut [Complex<Self::Float>]) {
+        let size = self.size();
+        assert_eq!(data.len() % size, 0, "data length must be a multiple of size()");
+        for segment in data.chunks_mut(size) {
+            self.fft_in_place(segment);
+        }
+    }
+
+    /// Batched counterpart to [`ifft_in_place`](Fft::ifft_in_place); see
+    /// [`fft_in_place_batch`](Fft::fft_in_place_batch).
+    fn ifft_in_place_batch(&mut self, data: &mut [Complex<Self::Float>]) {
+        let size = self.size();
+        assert_eq!(data.len() % size, 0, "data length must be a multiple of size()");
+        for segment in data.chunks_mut(size) {
+            self.ifft_in_place(segment);
+        }
+    }
 }
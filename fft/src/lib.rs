@@ -2,12 +2,25 @@
 mod vector;
 
 mod autosort;
+mod convolution;
 mod fft;
 mod float;
+mod mdct;
+mod multi;
+mod ntt;
+mod real;
 mod twiddle;
 
 use crate::autosort::prime_factor::PrimeFactorFft32;
+pub use crate::convolution::{
+    circular_convolve, convolve, convolve_real, cross_correlate, linear_convolve,
+    ConvolutionPlanner, OverlapAdd,
+};
 pub use crate::fft::Fft;
+pub use crate::mdct::Mdct32;
+pub use crate::multi::MultiFft;
+pub use crate::ntt::{Field, Mod998244353, ModInt, Modulus, Ntt, NttConvolutionPlanner, StaticModInt};
+pub use crate::real::{RealFft, RealFftF32};
 
 pub fn create_fft_f32(size: usize) -> Box<dyn Fft<Float = f32>> {
     Box::new(PrimeFactorFft32::new(size))
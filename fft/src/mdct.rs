@@ -0,0 +1,79 @@
+use crate::autosort::prime_factor::PrimeFactorFft32;
+use crate::fft::Fft;
+use num_complex::Complex;
+use std::f32::consts::PI;
+
+/// A modified discrete cosine transform (and its inverse) over `f32`, built
+/// on top of the complex FFT.
+///
+/// An `N`-coefficient MDCT of a length-`2N` real input is computed by
+/// zero-padding the input to `4N`, running a single complex FFT of that
+/// size, and extracting the odd-indexed bins with a post-twiddle that
+/// accounts for the transform's half-sample phase offset. The IMDCT mirrors
+/// this with a pre-twiddle before the same size `4N` FFT; overlap-add of the
+/// resulting `2N` samples with the neighboring frame is left to the caller.
+pub struct Mdct32 {
+    size: usize,
+    fft: PrimeFactorFft32,
+}
+
+impl Mdct32 {
+    /// Creates an MDCT/IMDCT pair for `size` coefficients (a `2 * size`
+    /// sample window).
+    pub fn new(size: usize) -> Self {
+        Self {
+            size,
+            fft: PrimeFactorFft32::new(4 * size),
+        }
+    }
+
+    /// The number of MDCT coefficients.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Computes the MDCT of `input` (length `2 * size()`) into `output`
+    /// (length `size()`).
+    pub fn mdct(&mut self, input: &[f32], output: &mut [f32]) {
+        let n = self.size;
+        assert_eq!(input.len(), 2 * n, "input must hold 2 * size() samples");
+        assert_eq!(output.len(), n, "output must hold size() coefficients");
+
+        let mut padded = vec![Complex::default(); 4 * n];
+        for (padded, &x) in padded.iter_mut().zip(input.iter()) {
+            *padded = Complex::new(x, 0.);
+        }
+        self.fft.fft_in_place(&mut padded);
+
+        for k in 0..n {
+            let m = 2 * k + 1;
+            let angle = PI * (m * (n + 1)) as f32 / (4 * n) as f32;
+            let twiddle = Complex::new(angle.cos(), -angle.sin());
+            output[k] = (twiddle * padded[m]).re;
+        }
+    }
+
+    /// Computes the IMDCT of `input` (length `size()`) into `output` (length
+    /// `2 * size()`), without windowing or overlap-add.
+    pub fn imdct(&mut self, input: &[f32], output: &mut [f32]) {
+        let n = self.size;
+        assert_eq!(input.len(), n, "input must hold size() coefficients");
+        assert_eq!(output.len(), 2 * n, "output must hold 2 * size() samples");
+
+        let mut padded = vec![Complex::default(); 4 * n];
+        for k in 0..n {
+            let angle = PI * k as f32 / 2.;
+            let twiddle = Complex::new(angle.cos(), -angle.sin());
+            padded[k] = Complex::new(input[k], 0.) * twiddle;
+        }
+        self.fft.fft_in_place(&mut padded);
+
+        let scale = 2. / n as f32;
+        for (out_n, output) in output.iter_mut().enumerate() {
+            let m = 2 * out_n + 1;
+            let angle = PI * (m + n) as f32 / (4 * n) as f32;
+            let twiddle = Complex::new(angle.cos(), -angle.sin());
+            *output = scale * (twiddle * padded[m]).re;
+        }
+    }
+}
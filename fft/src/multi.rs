@@ -0,0 +1,69 @@
+use crate::fft::Fft;
+use num_complex::Complex;
+use std::collections::HashMap;
+
+/// Plans a separable N-dimensional FFT over a row-major `Complex<f32>` buffer by
+/// composing one cached 1D [`Fft`] per distinct axis length and transforming each
+/// axis of the buffer in turn via strided in-place passes -- the standard
+/// "transform every row, then every column, then..." approach to multidimensional
+/// FFTs, built entirely on the existing 1D API rather than a dedicated ND engine.
+pub struct MultiFft {
+    /// Row-major dimensions: `dims[0]` is the slowest-varying axis, and
+    /// `dims[dims.len() - 1]` is contiguous in memory.
+    dims: Vec<usize>,
+    ffts: HashMap<usize, Box<dyn Fft<Float = f32>>>,
+}
+
+impl MultiFft {
+    /// Creates a planner for a row-major buffer with the given `dims`.
+    pub fn new(dims: Vec<usize>) -> Self {
+        assert!(!dims.is_empty(), "dims must not be empty");
+        let mut ffts: HashMap<usize, Box<dyn Fft<Float = f32>>> = HashMap::new();
+        for &len in &dims {
+            ffts.entry(len).or_insert_with(|| crate::create_fft_f32(len));
+        }
+        Self { dims, ffts }
+    }
+
+    /// The total number of elements the configured buffer holds.
+    pub fn len(&self) -> usize {
+        self.dims.iter().product()
+    }
+
+    /// Transforms `data` in place along every axis. Each axis's pass uses that
+    /// axis's own [`Fft::fft_in_place`]/[`Fft::ifft_in_place`], so the overall
+    /// `1 / len()` scaling an inverse transform needs falls out naturally as the
+    /// product of each axis's own `1 / axis_len` scaling, with no separate
+    /// whole-buffer pass required.
+    pub fn transform_in_place(&mut self, data: &mut [Complex<f32>], forward: bool) {
+        assert_eq!(data.len(), self.len(), "data must match the configured dimensions");
+
+        let mut outer = 1;
+        for (axis, &axis_len) in self.dims.iter().enumerate() {
+            let inner: usize = self.dims[axis + 1..].iter().product();
+            let fft = self
+                .ffts
+                .get_mut(&axis_len)
+                .expect("a planner was cached for every axis length in new()");
+
+            let mut buffer = vec![Complex::default(); axis_len];
+            for o in 0..outer {
+                for i in 0..inner {
+                    let base = o * axis_len * inner + i;
+                    for (k, sample) in buffer.iter_mut().enumerate() {
+                        *sample = data[base + k * inner];
+                    }
+                    if forward {
+                        fft.fft_in_place(&mut buffer);
+                    } else {
+                        fft.ifft_in_place(&mut buffer);
+                    }
+                    for (k, sample) in buffer.iter().enumerate() {
+                        data[base + k * inner] = *sample;
+                    }
+                }
+            }
+            outer *= axis_len;
+        }
+    }
+}
@@ -0,0 +1,471 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A finite field element, abstracting over the scalar type the NTT
+/// transforms. It is implemented here for the modular integers [`ModInt`]
+/// and [`StaticModInt`]; there is no implementation for `Complex` floats. The
+/// stage loop in [`Ntt`] is otherwise oblivious to which one it's working over.
+pub trait Field: Copy + Default + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self> {
+    fn one() -> Self;
+    fn from_usize(value: usize) -> Self;
+    fn inv(self) -> Self;
+    /// A primitive `n`-th root of unity.
+    fn root_of_unity(n: usize) -> Self;
+}
+
+/// An integer modulo the prime `M`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ModInt<const M: u64>(u64);
+
+impl<const M: u64> ModInt<M> {
+    pub fn new(value: u64) -> Self {
+        Self(value % M)
+    }
+
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    fn pow(self, mut exponent: u64) -> Self {
+        let mut base = self;
+        let mut result = Self::new(1);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    fn primitive_root() -> Self {
+        let mut factors = Vec::new();
+        let mut remaining = M - 1;
+        let mut p = 2;
+        while p * p <= remaining {
+            if remaining % p == 0 {
+                factors.push(p);
+                while remaining % p == 0 {
+                    remaining /= p;
+                }
+            }
+            p += 1;
+        }
+        if remaining > 1 {
+            factors.push(remaining);
+        }
+
+        'candidate: for g in 2..M {
+            let g = Self::new(g);
+            for &q in &factors {
+                if g.pow((M - 1) / q) == Self::new(1) {
+                    continue 'candidate;
+                }
+            }
+            return g;
+        }
+        unreachable!("M is prime, so a primitive root always exists")
+    }
+}
+
+impl<const M: u64> Default for ModInt<M> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<const M: u64> Add for ModInt<M> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self((self.0 + rhs.0) % M)
+    }
+}
+
+impl<const M: u64> Sub for ModInt<M> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self((self.0 + M - rhs.0) % M)
+    }
+}
+
+impl<const M: u64> Mul for ModInt<M> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self((self.0 as u128 * rhs.0 as u128 % M as u128) as u64)
+    }
+}
+
+impl<const M: u64> Neg for ModInt<M> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self((M - self.0) % M)
+    }
+}
+
+impl<const M: u64> Field for ModInt<M> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+
+    fn from_usize(value: usize) -> Self {
+        Self::new(value as u64 % M)
+    }
+
+    fn inv(self) -> Self {
+        // Fermat's little theorem: M is prime, so x^(M - 2) == x^-1 (mod M).
+        self.pow(M - 2)
+    }
+
+    fn root_of_unity(n: usize) -> Self {
+        assert_eq!((M - 1) % n as u64, 0, "M - 1 must be divisible by n");
+        Self::primitive_root().pow((M - 1) / n as u64)
+    }
+}
+
+/// Rolling twiddles for the radix-2 butterfly in
+/// [`apply_stages`]: `e[i]`/`ie[i]` are `2^i`-th roots of unity (forward and
+/// inverse) for `i` up to the 2-adic valuation of the transform size, found
+/// by repeatedly squaring down from the largest, and `sum_e`/`sum_ie` are the
+/// cumulative adjustment multiplied into the running twiddle `rot` as the
+/// butterfly advances from one group of a phase to the next — so each group
+/// costs one multiply instead of indexing an `O(size)` root table.
+struct RootInfo<F> {
+    e: Vec<F>,
+    ie: Vec<F>,
+    sum_e: Vec<F>,
+    sum_ie: Vec<F>,
+}
+
+impl<F: Field> RootInfo<F> {
+    fn new(size: usize) -> Self {
+        let rank = size.trailing_zeros() as usize;
+        let mut e = vec![F::default(); rank + 1];
+        let mut ie = vec![F::default(); rank + 1];
+        e[rank] = F::root_of_unity(1 << rank);
+        ie[rank] = e[rank].inv();
+        for i in (0..rank).rev() {
+            e[i] = e[i + 1] * e[i + 1];
+            ie[i] = ie[i + 1] * ie[i + 1];
+        }
+
+        let groups = rank.saturating_sub(1);
+        let mut sum_e = vec![F::default(); groups];
+        let mut sum_ie = vec![F::default(); groups];
+        let mut prod = F::one();
+        let mut iprod = F::one();
+        for i in 0..groups {
+            sum_e[i] = e[i + 2] * prod;
+            prod = prod * ie[i + 2];
+            sum_ie[i] = ie[i + 2] * iprod;
+            iprod = iprod * e[i + 2];
+        }
+
+        Self {
+            e,
+            ie,
+            sum_e,
+            sum_ie,
+        }
+    }
+}
+
+struct Stages<F> {
+    size: usize,
+    roots: RootInfo<F>,
+}
+
+impl<F: Field> Stages<F> {
+    fn new(size: usize) -> Option<Self> {
+        if size.count_ones() != 1 {
+            return None;
+        }
+        Some(Self {
+            size,
+            roots: RootInfo::new(size),
+        })
+    }
+}
+
+/// Transforms `data` in place: phase `ph` (`len` below) halves the active
+/// block width `p` and doubles the number of groups `s`, with the twiddle for
+/// each group advanced from the previous one via `sum_e`/`sum_ie` rather than
+/// looked up from a full root table. The butterflies produce bit-reversed
+/// output, so a final bit-reversal pass restores natural order: natural-order
+/// input yields natural-order output.
+fn apply_stages<F: Field>(data: &mut [F], stages: &Stages<F>, forward: bool) {
+    let size = stages.size;
+    assert_eq!(data.len(), size);
+    let h = size.trailing_zeros() as usize;
+    let sum = if forward {
+        &stages.roots.sum_e
+    } else {
+        &stages.roots.sum_ie
+    };
+
+    let mut len = 0;
+    while len < h {
+        let p = 1usize << (h - len - 1);
+        let mut rot = F::one();
+        for s in 0..(1usize << len) {
+            let offset = s << (h - len);
+            for i in 0..p {
+                let l = data[offset + i];
+                let r = data[offset + i + p] * rot;
+                data[offset + i] = l + r;
+                data[offset + i + p] = l - r;
+            }
+            if s + 1 != (1 << len) {
+                rot = rot * sum[s.trailing_ones() as usize];
+            }
+        }
+        len += 1;
+    }
+
+    // The butterflies above leave the result in bit-reversed order.
+    let mut j = 0;
+    for i in 1..size {
+        let mut bit = size >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j ^= bit;
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    if !forward {
+        let scale = F::from_usize(size).inv();
+        for x in data.iter_mut() {
+            *x = *x * scale;
+        }
+    }
+}
+
+/// A number-theoretic transform: a radix-2 Cooley-Tukey butterfly (plus a
+/// final bit-reversal pass) run over a [`Field`] instead of `Complex<f32>`, with twiddles
+/// advanced via the rolling [`RootInfo`] rather than indexed from a full
+/// root table. Unlike the floating-point path this is exact: no rounding
+/// error accumulates, which makes it suitable for exact integer convolution
+/// (see [`ConvolutionPlanner`]).
+pub struct Ntt<F> {
+    stages: Stages<F>,
+}
+
+impl<F: Field> Ntt<F> {
+    pub fn new(size: usize) -> Option<Self> {
+        let stages = Stages::new(size)?;
+        Some(Self { stages })
+    }
+
+    pub fn size(&self) -> usize {
+        self.stages.size
+    }
+
+    pub fn forward_in_place(&self, data: &mut [F]) {
+        apply_stages(data, &self.stages, true);
+    }
+
+    pub fn inverse_in_place(&self, data: &mut [F]) {
+        apply_stages(data, &self.stages, false);
+    }
+}
+
+/// An NTT-friendly prime that already knows a primitive root, so
+/// [`StaticModInt`] can exponentiate it directly instead of paying
+/// [`ModInt::primitive_root`]'s trial-factorization of `P - 1` on every
+/// [`Ntt::new`].
+pub trait Modulus: Copy {
+    const P: u64;
+    const PRIMITIVE_ROOT: u64;
+}
+
+/// An integer modulo [`Modulus::P`], generic over a [`Modulus`] that already
+/// carries its primitive root -- the same role [`ModInt`] plays, but without
+/// re-deriving the root at runtime. Plugs into [`Ntt`] exactly like [`ModInt`]
+/// does, since both just implement [`Field`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct StaticModInt<Mod: Modulus>(u64, std::marker::PhantomData<Mod>);
+
+impl<Mod: Modulus> StaticModInt<Mod> {
+    pub fn new(value: u64) -> Self {
+        Self(value % Mod::P, std::marker::PhantomData)
+    }
+
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    fn pow(self, mut exponent: u64) -> Self {
+        let mut base = self;
+        let mut result = Self::new(1);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        result
+    }
+}
+
+impl<Mod: Modulus> Default for StaticModInt<Mod> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<Mod: Modulus> Add for StaticModInt<Mod> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self((self.0 + rhs.0) % Mod::P, std::marker::PhantomData)
+    }
+}
+
+impl<Mod: Modulus> Sub for StaticModInt<Mod> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self((self.0 + Mod::P - rhs.0) % Mod::P, std::marker::PhantomData)
+    }
+}
+
+impl<Mod: Modulus> Mul for StaticModInt<Mod> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self((self.0 as u128 * rhs.0 as u128 % Mod::P as u128) as u64, std::marker::PhantomData)
+    }
+}
+
+impl<Mod: Modulus> Neg for StaticModInt<Mod> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self((Mod::P - self.0) % Mod::P, std::marker::PhantomData)
+    }
+}
+
+impl<Mod: Modulus> Field for StaticModInt<Mod> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+
+    fn from_usize(value: usize) -> Self {
+        Self::new(value as u64 % Mod::P)
+    }
+
+    fn inv(self) -> Self {
+        // Fermat's little theorem: P is prime, so x^(P - 2) == x^-1 (mod P).
+        self.pow(Mod::P - 2)
+    }
+
+    fn root_of_unity(n: usize) -> Self {
+        assert_eq!((Mod::P - 1) % n as u64, 0, "P - 1 must be divisible by n");
+        Self::new(Mod::PRIMITIVE_ROOT).pow((Mod::P - 1) / n as u64)
+    }
+}
+
+/// The modulus used throughout competitive-programming NTT code: `998244353
+/// = 119 * 2^23 + 1`, with well-known primitive root `3`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Mod998244353;
+
+impl Modulus for Mod998244353 {
+    const P: u64 = 998244353;
+    const PRIMITIVE_ROOT: u64 = 3;
+}
+
+/// Three NTT-friendly primes of the form `c * 2^k + 1`, each supporting
+/// power-of-two transforms far larger than any convolution this planner is
+/// likely to be asked for.
+const P1: u64 = 167772161;
+const P2: u64 = 469762049;
+const P3: u64 = 998244353;
+
+fn inv_mod(value: u64, modulus: u64) -> u64 {
+    let (mut old_r, mut r) = (value as i128, modulus as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        let new_r = old_r - quotient * r;
+        old_r = r;
+        r = new_r;
+        let new_s = old_s - quotient * s;
+        old_s = s;
+        s = new_s;
+    }
+    ((old_s % modulus as i128 + modulus as i128) % modulus as i128) as u64
+}
+
+fn convolve_prime<const M: u64>(ntt: &Ntt<ModInt<M>>, size: usize, a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut x: Vec<ModInt<M>> = (0..size).map(|i| ModInt::new(*a.get(i).unwrap_or(&0))).collect();
+    let mut y: Vec<ModInt<M>> = (0..size).map(|i| ModInt::new(*b.get(i).unwrap_or(&0))).collect();
+    ntt.forward_in_place(&mut x);
+    ntt.forward_in_place(&mut y);
+    for (x, y) in x.iter_mut().zip(y.iter()) {
+        *x = *x * *y;
+    }
+    ntt.inverse_in_place(&mut x);
+    x.iter().map(|v| v.value()).collect()
+}
+
+/// Convolves sequences of `u64` coefficients under an arbitrary `u64`
+/// modulus, by running the same NTT convolution under three distinct
+/// NTT-friendly primes and reconstructing the integer coefficient with
+/// Garner's algorithm (the standard three-prime CRT trick) before the final
+/// reduction. The reconstruction is exact only while every true coefficient
+/// of `a (*) b` is below `P1 * P2 * P3` (about `7.9e25`); inputs are not
+/// reduced by `modulus` first.
+///
+/// Caches the three inner transforms so repeated convolutions of the same
+/// (padded) size avoid replanning.
+pub struct NttConvolutionPlanner {
+    size: usize,
+    ntt1: Ntt<ModInt<P1>>,
+    ntt2: Ntt<ModInt<P2>>,
+    ntt3: Ntt<ModInt<P3>>,
+    inv_p1_mod_p2: u64,
+    inv_p1p2_mod_p3: u64,
+}
+
+impl NttConvolutionPlanner {
+    /// Creates a planner able to convolve sequences whose result has up to
+    /// `max_result_len` coefficients.
+    pub fn new(max_result_len: usize) -> Self {
+        let size = max_result_len.next_power_of_two();
+        let p1p2_mod_p3 = (P1 as u128 * P2 as u128 % P3 as u128) as u64;
+        Self {
+            size,
+            ntt1: Ntt::new(size).expect("size is a power of two"),
+            ntt2: Ntt::new(size).expect("size is a power of two"),
+            ntt3: Ntt::new(size).expect("size is a power of two"),
+            inv_p1_mod_p2: inv_mod(P1 % P2, P2),
+            inv_p1p2_mod_p3: inv_mod(p1p2_mod_p3, P3),
+        }
+    }
+
+    /// Computes `a (*) b`, reduced modulo `modulus`.
+    pub fn convolve_mod(&self, a: &[u64], b: &[u64], modulus: u64) -> Vec<u64> {
+        let result_len = a.len() + b.len() - 1;
+        assert!(result_len <= self.size, "planner was built for a smaller result");
+
+        let r1 = convolve_prime(&self.ntt1, self.size, a, b);
+        let r2 = convolve_prime(&self.ntt2, self.size, a, b);
+        let r3 = convolve_prime(&self.ntt3, self.size, a, b);
+
+        let p1p2 = P1 as u128 * P2 as u128;
+        (0..result_len)
+            .map(|i| {
+                // Garner's algorithm: combine r1, r2 modulo p1 * p2, then fold in r3.
+                let x1 = r1[i] as u128;
+                let t2 = ((r2[i] + P2 - r1[i] % P2) % P2) as u128 * self.inv_p1_mod_p2 as u128 % P2 as u128;
+                let x12 = x1 + P1 as u128 * t2;
+                let t3 = ((r3[i] as u128 + P3 as u128 - x12 % P3 as u128) % P3 as u128)
+                    * self.inv_p1p2_mod_p3 as u128
+                    % P3 as u128;
+                let x = x12 + p1p2 * t3;
+                (x % modulus as u128) as u64
+            })
+            .collect()
+    }
+}
@@ -36,6 +36,12 @@ pub unsafe fn butterfly_avx(x: [__m256; 2], _forward: bool) -> [__m256; 2] {
     [_mm256_add_ps(x[0], x[1]), _mm256_sub_ps(x[0], x[1])]
 }
 
+#[multiversion::target("[x86|x86_64]+avx")]
+#[inline]
+pub unsafe fn butterfly_avx_f64(x: [__m256d; 2], _forward: bool) -> [__m256d; 2] {
+    [_mm256_add_pd(x[0], x[1]), _mm256_sub_pd(x[0], x[1])]
+}
+
 #[inline]
 pub fn radix2<T: FftFloat>(x: &[Complex<T>], y: &mut [Complex<T>], config: &Radix2<T>) {
     crate::implement_generic! {2, x, y, &config.base, butterfly}
@@ -52,3 +58,15 @@ unsafe fn radix2_f32_avx(x: &[Complex<f32>], y: &mut [Complex<f32>], config: &Ra
 pub fn radix2_f32(x: &[Complex<f32>], y: &mut [Complex<f32>], config: &Radix2<f32>) {
     radix2(x, y, config);
 }
+
+#[multiversion::target("[x86|x86_64]+avx")]
+unsafe fn radix2_f64_avx(x: &[Complex<f64>], y: &mut [Complex<f64>], config: &Radix2<f64>) {
+    crate::implement_avx_f64! {2, x, y, &config.base, butterfly_avx_f64}
+}
+
+#[multiversion::multiversion(
+    "[x86|x86_64]+avx" => radix2_f64_avx
+)]
+pub fn radix2_f64(x: &[Complex<f64>], y: &mut [Complex<f64>], config: &Radix2<f64>) {
+    radix2(x, y, config);
+}
@@ -127,3 +127,87 @@ unsafe fn radix3_f32_fma(x: &[Complex<f32>], y: &mut [Complex<f32>], config: &Ra
 pub fn radix3_f32(x: &[Complex<f32>], y: &mut [Complex<f32>], config: &Radix3<f32>) {
     radix3(x, y, config);
 }
+
+#[multiversion::target("[x86|x86_64]+avx")]
+unsafe fn radix3_f64_avx(x: &[Complex<f64>], y: &mut [Complex<f64>], config: &Radix3<f64>) {
+    #[static_dispatch]
+    use crate::avx::mul_pd;
+
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    let twiddle = _mm256_blend_pd(
+        _mm256_set1_pd(config.twiddle.re),
+        _mm256_set1_pd(config.twiddle.im),
+        0xa,
+    );
+    let twiddle_conj = _mm256_blend_pd(
+        _mm256_set1_pd(config.twiddle.re),
+        _mm256_set1_pd(-config.twiddle.im),
+        0xa,
+    );
+
+    let bfly = |x: [__m256d; 3], _forward: bool| -> [__m256d; 3] {
+        [
+            _mm256_add_pd(x[0], _mm256_add_pd(x[1], x[2])),
+            _mm256_add_pd(
+                x[0],
+                _mm256_add_pd(mul_pd(x[1], twiddle), mul_pd(x[2], twiddle_conj)),
+            ),
+            _mm256_add_pd(
+                x[0],
+                _mm256_add_pd(mul_pd(x[1], twiddle_conj), mul_pd(x[2], twiddle)),
+            ),
+        ]
+    };
+
+    crate::implement_avx_f64! {3, x, y, &config.base, bfly}
+}
+
+#[multiversion::target("[x86|x86_64]+avx+fma")]
+unsafe fn radix3_f64_fma(x: &[Complex<f64>], y: &mut [Complex<f64>], config: &Radix3<f64>) {
+    #[static_dispatch]
+    use crate::avx::mul_pd;
+
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    let twiddle = _mm256_blend_pd(
+        _mm256_set1_pd(config.twiddle.re),
+        _mm256_set1_pd(config.twiddle.im),
+        0xa,
+    );
+    let twiddle_conj = _mm256_blend_pd(
+        _mm256_set1_pd(config.twiddle.re),
+        _mm256_set1_pd(-config.twiddle.im),
+        0xa,
+    );
+
+    let bfly = |x: [__m256d; 3], _forward: bool| -> [__m256d; 3] {
+        [
+            _mm256_add_pd(x[0], _mm256_add_pd(x[1], x[2])),
+            _mm256_add_pd(
+                x[0],
+                _mm256_add_pd(mul_pd(x[1], twiddle), mul_pd(x[2], twiddle_conj)),
+            ),
+            _mm256_add_pd(
+                x[0],
+                _mm256_add_pd(mul_pd(x[1], twiddle_conj), mul_pd(x[2], twiddle)),
+            ),
+        ]
+    };
+
+    crate::implement_avx_f64! {3, x, y, &config.base, bfly}
+}
+
+#[multiversion::multiversion(
+    "[x86|x86_64]+avx" => radix3_f64_avx,
+    "[x86|x86_64]+avx+fma" => radix3_f64_fma
+)]
+pub fn radix3_f64(x: &[Complex<f64>], y: &mut [Complex<f64>], config: &Radix3<f64>) {
+    radix3(x, y, config);
+}
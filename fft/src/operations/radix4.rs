@@ -54,6 +54,12 @@ pub unsafe fn butterfly_avx(x: [__m256; 4], forward: bool) -> [__m256; 4] {
     make_butterfly!(x, forward, radix2::butterfly_avx, avx::rotate)
 }
 
+#[multiversion::target("[x86|x86_64]+avx")]
+#[inline]
+pub unsafe fn butterfly_avx_f64(x: [__m256d; 4], forward: bool) -> [__m256d; 4] {
+    make_butterfly!(x, forward, radix2::butterfly_avx_f64, avx::rotate_pd)
+}
+
 #[inline]
 pub fn radix4<T: FftFloat>(x: &[Complex<T>], y: &mut [Complex<T>], config: &Radix4<T>) {
     crate::implement_generic! {4, x, y, &config.base, butterfly}
@@ -80,3 +86,25 @@ unsafe fn radix4_f32_fma(x: &[Complex<f32>], y: &mut [Complex<f32>], config: &Ra
 pub fn radix4_f32(x: &[Complex<f32>], y: &mut [Complex<f32>], config: &Radix4<f32>) {
     radix4(x, y, config);
 }
+
+#[multiversion::target("[x86|x86_64]+avx")]
+unsafe fn radix4_f64_avx(x: &[Complex<f64>], y: &mut [Complex<f64>], config: &Radix4<f64>) {
+    #[static_dispatch]
+    use crate::avx::mul_pd;
+    crate::implement_avx_f64! {4, x, y, &config.base, butterfly_avx_f64}
+}
+
+#[multiversion::target("[x86|x86_64]+avx+fma")]
+unsafe fn radix4_f64_fma(x: &[Complex<f64>], y: &mut [Complex<f64>], config: &Radix4<f64>) {
+    #[static_dispatch]
+    use crate::avx::mul_pd;
+    crate::implement_avx_f64! {4, x, y, &config.base, butterfly_avx_f64}
+}
+
+#[multiversion::multiversion(
+    "[x86|x86_64]+avx" => radix4_f64_avx,
+    "[x86|x86_64]+avx+fma" => radix4_f64_fma
+)]
+pub fn radix4_f64(x: &[Complex<f64>], y: &mut [Complex<f64>], config: &Radix4<f64>) {
+    radix4(x, y, config);
+}
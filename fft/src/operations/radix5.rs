@@ -0,0 +1,127 @@
+use super::BaseConfig;
+use crate::float::FftFloat;
+use num_complex::Complex;
+
+pub struct Radix5<T> {
+    base: BaseConfig<T>,
+    twiddle1: Complex<T>,
+    twiddle2: Complex<T>,
+}
+
+impl<T: FftFloat> Radix5<T> {
+    fn new(size: usize, stride: usize, forward: bool) -> Self {
+        Self {
+            base: BaseConfig::new(size, stride, 5, forward),
+            twiddle1: super::compute_twiddle(1, 5, forward),
+            twiddle2: super::compute_twiddle(2, 5, forward),
+        }
+    }
+
+    pub fn forward(size: usize, stride: usize) -> Self {
+        Self::new(size, stride, true)
+    }
+
+    pub fn inverse(size: usize, stride: usize) -> Self {
+        Self::new(size, stride, false)
+    }
+}
+
+#[inline]
+pub fn radix5<T: FftFloat>(
+    x: &[Complex<T>],
+    y: &mut [Complex<T>],
+    Radix5 {
+        base: config,
+        twiddle1,
+        twiddle2,
+    }: &Radix5<T>,
+) {
+    let bfly = |x: [Complex<T>; 5], _forward: bool| -> [Complex<T>; 5] {
+        let w1 = *twiddle1;
+        let w2 = *twiddle2;
+        let w3 = twiddle2.conj();
+        let w4 = twiddle1.conj();
+        [
+            x[0] + x[1] + x[2] + x[3] + x[4],
+            x[0] + x[1] * w1 + x[2] * w2 + x[3] * w3 + x[4] * w4,
+            x[0] + x[1] * w2 + x[2] * w4 + x[3] * w1 + x[4] * w3,
+            x[0] + x[1] * w3 + x[2] * w1 + x[3] * w4 + x[4] * w2,
+            x[0] + x[1] * w4 + x[2] * w3 + x[3] * w2 + x[4] * w1,
+        ]
+    };
+
+    crate::implement_generic! {5, x, y, config, bfly}
+}
+
+#[multiversion::target("[x86|x86_64]+avx")]
+unsafe fn radix5_f32_avx(x: &[Complex<f32>], y: &mut [Complex<f32>], config: &Radix5<f32>) {
+    #[static_dispatch]
+    use crate::avx::mul;
+
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    let blend = |t: Complex<f32>| {
+        _mm256_blend_ps(_mm256_set1_ps(t.re), _mm256_set1_ps(t.im), 0xaa)
+    };
+    let w1 = blend(config.twiddle1);
+    let w2 = blend(config.twiddle2);
+    let w3 = blend(config.twiddle2.conj());
+    let w4 = blend(config.twiddle1.conj());
+
+    let bfly = |x: [__m256; 5], _forward: bool| -> [__m256; 5] {
+        [
+            _mm256_add_ps(x[0], _mm256_add_ps(_mm256_add_ps(x[1], x[2]), _mm256_add_ps(x[3], x[4]))),
+            _mm256_add_ps(
+                x[0],
+                _mm256_add_ps(
+                    _mm256_add_ps(mul(x[1], w1), mul(x[2], w2)),
+                    _mm256_add_ps(mul(x[3], w3), mul(x[4], w4)),
+                ),
+            ),
+            _mm256_add_ps(
+                x[0],
+                _mm256_add_ps(
+                    _mm256_add_ps(mul(x[1], w2), mul(x[2], w4)),
+                    _mm256_add_ps(mul(x[3], w1), mul(x[4], w3)),
+                ),
+            ),
+            _mm256_add_ps(
+                x[0],
+                _mm256_add_ps(
+                    _mm256_add_ps(mul(x[1], w3), mul(x[2], w1)),
+                    _mm256_add_ps(mul(x[3], w4), mul(x[4], w2)),
+                ),
+            ),
+            _mm256_add_ps(
+                x[0],
+                _mm256_add_ps(
+                    _mm256_add_ps(mul(x[1], w4), mul(x[2], w3)),
+                    _mm256_add_ps(mul(x[3], w2), mul(x[4], w1)),
+                ),
+            ),
+        ]
+    };
+
+    crate::implement_avx_f32! {5, x, y, &config.base, bfly}
+}
+
+#[multiversion::target("[x86|x86_64]+avx+fma")]
+unsafe fn radix5_f32_fma(x: &[Complex<f32>], y: &mut [Complex<f32>], config: &Radix5<f32>) {
+    radix5_f32_avx(x, y, config);
+}
+
+#[multiversion::multiversion(
+    "[x86|x86_64]+avx" => radix5_f32_avx,
+    "[x86|x86_64]+avx+fma" => radix5_f32_fma
+)]
+pub fn radix5_f32(x: &[Complex<f32>], y: &mut [Complex<f32>], config: &Radix5<f32>) {
+    radix5(x, y, config);
+}
+
+#[inline]
+pub fn radix5_f64(x: &[Complex<f64>], y: &mut [Complex<f64>], config: &Radix5<f64>) {
+    radix5(x, y, config);
+}
@@ -0,0 +1,73 @@
+use super::BaseConfig;
+use crate::float::FftFloat;
+use num_complex::Complex;
+
+pub struct Radix7<T> {
+    base: BaseConfig<T>,
+    twiddles: [Complex<T>; 3],
+}
+
+impl<T: FftFloat> Radix7<T> {
+    fn new(size: usize, stride: usize, forward: bool) -> Self {
+        Self {
+            base: BaseConfig::new(size, stride, 7, forward),
+            twiddles: [
+                super::compute_twiddle(1, 7, forward),
+                super::compute_twiddle(2, 7, forward),
+                super::compute_twiddle(3, 7, forward),
+            ],
+        }
+    }
+
+    pub fn forward(size: usize, stride: usize) -> Self {
+        Self::new(size, stride, true)
+    }
+
+    pub fn inverse(size: usize, stride: usize) -> Self {
+        Self::new(size, stride, false)
+    }
+}
+
+#[inline]
+pub fn radix7<T: FftFloat>(
+    x: &[Complex<T>],
+    y: &mut [Complex<T>],
+    Radix7 {
+        base: config,
+        twiddles,
+    }: &Radix7<T>,
+) {
+    let bfly = |x: [Complex<T>; 7], _forward: bool| -> [Complex<T>; 7] {
+        // w[k] for k in 1..=6, where w[k] = twiddles[k - 1] for k <= 3 and
+        // w[k] = twiddles[7 - k - 1].conj() for k > 3.
+        let w = |k: usize| -> Complex<T> {
+            if k <= 3 {
+                twiddles[k - 1]
+            } else {
+                twiddles[7 - k - 1].conj()
+            }
+        };
+        let mut out = [Complex::default(); 7];
+        out[0] = x[0] + x[1] + x[2] + x[3] + x[4] + x[5] + x[6];
+        for j in 1..7 {
+            let mut acc = x[0];
+            for k in 1..7 {
+                acc = acc + x[k] * w((j * k) % 7);
+            }
+            out[j] = acc;
+        }
+        out
+    };
+
+    crate::implement_generic! {7, x, y, config, bfly}
+}
+
+#[inline]
+pub fn radix7_f32(x: &[Complex<f32>], y: &mut [Complex<f32>], config: &Radix7<f32>) {
+    radix7(x, y, config);
+}
+
+#[inline]
+pub fn radix7_f64(x: &[Complex<f64>], y: &mut [Complex<f64>], config: &Radix7<f64>) {
+    radix7(x, y, config);
+}
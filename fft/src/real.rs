@@ -0,0 +1,144 @@
+use crate::autosort::prime_factor::PrimeFactorFft32;
+use crate::fft::Fft;
+use crate::twiddle::compute_twiddle;
+use num_complex::Complex;
+
+/// A real-input FFT, exploiting Hermitian symmetry to halve the work of a
+/// full complex transform.
+pub trait RealFft {
+    type Float;
+
+    /// Transforms `size` real samples into the `size / 2 + 1` non-redundant
+    /// complex spectrum bins.
+    fn rfft(&mut self, input: &[Self::Float], output: &mut [Complex<Self::Float>]);
+
+    /// Reconstructs `size` real samples from the `size / 2 + 1` non-redundant
+    /// complex spectrum bins produced by [`rfft`](RealFft::rfft).
+    fn irfft(&mut self, input: &[Complex<Self::Float>], output: &mut [Self::Float]);
+
+    /// The scratch space (in complex elements) [`rfft_with_scratch`](RealFft::rfft_with_scratch)
+    /// and [`irfft_with_scratch`](RealFft::irfft_with_scratch) require.
+    fn scratch_len(&self) -> usize;
+
+    /// `&self` counterpart to [`rfft`](RealFft::rfft), using caller-owned `scratch`
+    /// (at least [`scratch_len`](RealFft::scratch_len) elements) instead of scratch space
+    /// owned by `self` -- like [`Fft::process_with_scratch`](crate::Fft::process_with_scratch),
+    /// this lets one planner serve several concurrent transforms.
+    fn rfft_with_scratch(
+        &self,
+        input: &[Self::Float],
+        output: &mut [Complex<Self::Float>],
+        scratch: &mut [Complex<Self::Float>],
+    );
+
+    /// `&self` counterpart to [`irfft`](RealFft::irfft); see
+    /// [`rfft_with_scratch`](RealFft::rfft_with_scratch).
+    fn irfft_with_scratch(
+        &self,
+        input: &[Complex<Self::Float>],
+        output: &mut [Self::Float],
+        scratch: &mut [Complex<Self::Float>],
+    );
+}
+
+/// A real-input FFT over `f32`.
+///
+/// Packs the `size` real samples into `size / 2` complex samples, runs a
+/// [`PrimeFactorFft32`] of that half size, and recombines the even/odd
+/// spectra into the non-redundant output bins. Requires an even `size`.
+pub struct RealFftF32 {
+    size: usize,
+    inner: PrimeFactorFft32,
+    twiddles: Box<[Complex<f32>]>,
+}
+
+impl RealFftF32 {
+    pub fn new(size: usize) -> Self {
+        assert_eq!(size % 2, 0, "RealFftF32 requires an even size");
+        let half = size / 2;
+        let twiddles = (0..=half)
+            .map(|k| compute_twiddle(k, size, true))
+            .collect::<Vec<_>>()
+            .into_boxed_slice();
+        Self {
+            size,
+            inner: PrimeFactorFft32::new(half),
+            twiddles,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl RealFft for RealFftF32 {
+    type Float = f32;
+
+    fn rfft(&mut self, input: &[f32], output: &mut [Complex<f32>]) {
+        let mut scratch = vec![Complex::default(); self.scratch_len()];
+        self.rfft_with_scratch(input, output, &mut scratch);
+    }
+
+    fn irfft(&mut self, input: &[Complex<f32>], output: &mut [f32]) {
+        let mut scratch = vec![Complex::default(); self.scratch_len()];
+        self.irfft_with_scratch(input, output, &mut scratch);
+    }
+
+    fn scratch_len(&self) -> usize {
+        self.size / 2 + self.inner.scratch_len()
+    }
+
+    fn rfft_with_scratch(&self, input: &[f32], output: &mut [Complex<f32>], scratch: &mut [Complex<f32>]) {
+        let half = self.size / 2;
+        assert_eq!(input.len(), self.size, "input must match configured size");
+        assert_eq!(output.len(), half + 1, "output must hold size / 2 + 1 bins");
+        assert!(scratch.len() >= self.scratch_len(), "scratch must hold at least scratch_len() elements");
+
+        let (z, inner_scratch) = scratch.split_at_mut(half);
+        for (k, z) in z.iter_mut().enumerate() {
+            *z = Complex::new(input[2 * k], input[2 * k + 1]);
+        }
+        self.inner.process_with_scratch(z, inner_scratch, true);
+
+        output[0] = Complex::new(z[0].re + z[0].im, 0.);
+        output[half] = Complex::new(z[0].re - z[0].im, 0.);
+        for k in 1..half {
+            let zk = z[k];
+            let zm = z[half - k];
+            let even = (zk + zm.conj()) * 0.5;
+            let odd = (zk - zm.conj()) * 0.5;
+            let twiddle = Complex::new(0., -1.) * self.twiddles[k];
+            output[k] = even + twiddle * odd;
+        }
+    }
+
+    fn irfft_with_scratch(&self, input: &[Complex<f32>], output: &mut [f32], scratch: &mut [Complex<f32>]) {
+        let half = self.size / 2;
+        assert_eq!(input.len(), half + 1, "input must hold size / 2 + 1 bins");
+        assert_eq!(output.len(), self.size, "output must match configured size");
+        assert!(scratch.len() >= self.scratch_len(), "scratch must hold at least scratch_len() elements");
+
+        let (z, inner_scratch) = scratch.split_at_mut(half);
+        z[0] = Complex::new(
+            (input[0].re + input[half].re) * 0.5,
+            (input[0].re - input[half].re) * 0.5,
+        );
+        for k in 1..half {
+            let m = half - k;
+            let twiddle_k = Complex::new(0., -1.) * self.twiddles[k];
+            let twiddle_m = Complex::new(0., -1.) * self.twiddles[m];
+            let xk = input[k];
+            let xm_conj = input[m].conj();
+            let odd = (xk - xm_conj) / (twiddle_k + twiddle_m.conj());
+            let even = xk - twiddle_k * odd;
+            z[k] = even + odd;
+        }
+        self.inner.process_with_scratch(z, inner_scratch, false);
+
+        for (k, z) in z.iter().enumerate() {
+            output[2 * k] = z.re;
+            output[2 * k + 1] = z.im;
+        }
+    }
+}
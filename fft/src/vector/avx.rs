@@ -40,16 +40,12 @@ impl super::ComplexVector for Avx32 {
         Self(_mm256_sub_ps(self.0, rhs.0))
     }
 
-    #[multiversion::target("[x86|x86_64]+avx")]
+    #[multiversion::target_clones("[x86|x86_64]+avx", "[x86|x86_64]+avx+fma")]
     #[inline]
     unsafe fn mul(&self, rhs: &Self) -> Self {
-        let re = _mm256_moveldup_ps(self.0);
-        let im = _mm256_movehdup_ps(self.0);
-        let sh = _mm256_permute_ps(rhs.0, 0xb1);
-        Self(_mm256_addsub_ps(
-            _mm256_mul_ps(re, rhs.0),
-            _mm256_mul_ps(im, sh),
-        ))
+        #[static_dispatch]
+        use crate::avx::mul;
+        Self(mul(self.0, rhs.0))
     }
 
     #[multiversion::target("[x86|x86_64]+avx")]
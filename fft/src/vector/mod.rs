@@ -26,3 +26,41 @@ pub trait ComplexVector: Copy {
     unsafe fn partial_load(from: *const Complex<Self::Float>, count: usize) -> Self;
     unsafe fn partial_store(&self, to: *mut Complex<Self::Float>, count: usize);
 }
+
+/// A seam for butterflies that multiply by `±i` as part of their combine step
+/// (`autosort::radix4::Radix4` today), so that single call site reads the same
+/// regardless of whether rotation is free.
+///
+/// This is scaffolding, not the generalization itself: `CyclicAlgebra` is still
+/// a supertrait of `ComplexVector`, so only a `ComplexVector` can implement it,
+/// and `impl<V: ComplexVector> CyclicAlgebra for V {}` means nothing else ever
+/// will -- `HAS_FAST_ROTATE` is therefore always `true` in this crate today. A
+/// modular-integer NTT scalar with no `±i` shortcut would need `CyclicAlgebra`
+/// decoupled from `ComplexVector` first (its `load`/`store`/`broadcast` are
+/// hardwired to `Complex<Self::Float>`), which is a follow-up in its own right.
+/// `autosort::radix2::Radix2` and `autosort::radix3::Radix3` still call
+/// `rotate`/multiply directly rather than going through
+/// [`rotate_or_twiddle`](CyclicAlgebra::rotate_or_twiddle), and the older,
+/// macro-generated `autosort::radix8`/`radix16` butterflies and
+/// `Autosort`/`Bluesteins` themselves are untouched -- none of that is wired
+/// into this trait yet.
+pub trait CyclicAlgebra: ComplexVector {
+    /// Whether `rotate` is cheaper than multiplying by the equivalent `±i`
+    /// twiddle. `Radix4`/`Radix8` should consult this through
+    /// [`rotate_or_twiddle`](CyclicAlgebra::rotate_or_twiddle) rather than
+    /// calling `rotate` unconditionally.
+    const HAS_FAST_ROTATE: bool = true;
+
+    /// Rotates by `±i` using the fast path when the algebra has one,
+    /// otherwise falls back to `twiddle()`, a lazily-built twiddle multiply
+    /// equivalent to the same rotation.
+    unsafe fn rotate_or_twiddle(&self, positive: bool, twiddle: impl FnOnce() -> Self) -> Self {
+        if Self::HAS_FAST_ROTATE {
+            self.rotate(positive)
+        } else {
+            self.mul(&twiddle())
+        }
+    }
+}
+
+impl<V: ComplexVector> CyclicAlgebra for V {}
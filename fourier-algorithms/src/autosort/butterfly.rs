@@ -1,4 +1,6 @@
 use crate::float::Float;
+use num_traits::One as _;
+
 use generic_simd::{
     arch,
     vector::{
@@ -449,6 +451,92 @@ pub(crate) fn apply_butterfly_narrow<T, Token, B>(
     }
 }
 
+/// The largest radix any [`Butterfly`] impl here uses -- the size of the fixed buffer
+/// [`apply_butterfly_narrow_incremental`] needs for one block's worth of twiddles.
+const MAX_RADIX: usize = 8;
+
+/// Memory-light alternative to [`apply_butterfly_narrow`]: instead of reading this
+/// stage's `size`-length twiddle table, each block's twiddles are regenerated from a
+/// single per-stage rotation. Block `i`'s fundamental twiddle is `base^i`, where
+/// `base = compute_twiddle(1, size, forward)`, since
+/// `compute_twiddle(i, size, forward) == compute_twiddle(1, size, forward)^i` -- so
+/// advancing from block `i` to block `i + 1` is one complex multiply by `base` rather
+/// than a table read, trading the stage's `O(size)` twiddle table for `O(1)` extra
+/// state (plus the handful of powers of `base` needed within a single block).
+#[inline(always)]
+pub(crate) fn apply_butterfly_narrow_incremental<T, Token, B>(
+    _butterfly: B,
+    token: Token,
+    input: &[nc::Complex<T>],
+    output: &mut [nc::Complex<T>],
+    size: usize,
+    stride: usize,
+    forward: bool,
+) where
+    T: Float,
+    Token: arch::Token,
+    nc::Complex<T>: ScalarWidth<Token, width::W1>,
+    B: Butterfly<T, width::W1, Token>,
+    SizedVector<nc::Complex<T>, width::W1, Token>: Complex,
+{
+    let radix = B::radix();
+    let m = size / radix;
+    let base = crate::twiddle::compute_twiddle(1, size, forward);
+    let mut block_root = nc::Complex::<T>::one();
+
+    // Each block multiplies `block_root` by `base` rather than recomputing it, so
+    // rounding error accumulates across the run -- by a few hundred blocks in, `block_root`
+    // has drifted measurably off the unit circle, and the drift keeps growing with `size`.
+    // Re-deriving it directly from `compute_twiddle` every `RENORM_INTERVAL` blocks bounds
+    // how many incremental multiplies can ever accumulate, independent of `size`.
+    const RENORM_INTERVAL: usize = 256;
+
+    let mut input = input.as_ptr();
+    let mut output = output.as_mut_ptr();
+    for i in 0..m {
+        if i % RENORM_INTERVAL == 0 {
+            block_root = crate::twiddle::compute_twiddle(i, size, forward);
+        }
+
+        let mut twiddles = [nc::Complex::<T>::one(); MAX_RADIX];
+        let mut power = nc::Complex::<T>::one();
+        for t in twiddles.iter_mut().take(radix) {
+            *t = power;
+            power *= block_root;
+        }
+
+        let mut scratch = B::make_buffer(token);
+        let mut read = input;
+        for k in 0..radix {
+            unsafe {
+                scratch.as_mut()[k] = read.read().splat(token);
+                read = read.add(stride * m);
+            }
+        }
+
+        scratch = B::apply(token, scratch, forward);
+        if size != radix {
+            for (s, t) in scratch.as_mut().iter_mut().zip(twiddles.iter()) {
+                *s *= t.splat(token);
+            }
+        }
+
+        let mut write = output;
+        for k in 0..radix {
+            unsafe {
+                write.write(scratch.as_ref()[k][0]);
+                write = write.add(stride);
+            }
+        }
+
+        unsafe {
+            input = input.add(stride);
+            output = output.add(stride * radix);
+        }
+        block_root *= base;
+    }
+}
+
 macro_rules! implement {
     // the token must be passed in due to something with macro hygiene
     {
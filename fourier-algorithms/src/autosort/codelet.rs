@@ -0,0 +1,32 @@
+//! A registry of precomputed radix-count plans for common FFT sizes.
+//!
+//! [`super::Configuration::new`] consults [`lookup`] before running the
+//! generic greedy factorization loop. A hit is a small, fixed multi-step
+//! plan -- the same shape the factorization loop would otherwise search
+//! for -- so a size that's called often (audio/codec block sizes, powers of
+//! two) skips straight to its plan instead of re-deriving it every time a
+//! configuration is built.
+
+use super::NUM_RADICES;
+
+const REGISTRY: &[(usize, [usize; NUM_RADICES])] = &[
+    (64, [1, 1, 0, 0, 1]),
+    (128, [1, 1, 1, 0, 0]),
+    (256, [1, 2, 0, 0, 0]),
+    (512, [1, 2, 0, 0, 1]),
+    (1024, [1, 2, 1, 0, 0]),
+    (2048, [1, 3, 0, 0, 0]),
+    (4096, [1, 3, 0, 0, 1]),
+];
+
+/// Looks up the registered radix-count plan for `size`, if there is one.
+pub(super) const fn lookup(size: usize) -> Option<[usize; NUM_RADICES]> {
+    let mut i = 0;
+    while i < REGISTRY.len() {
+        if REGISTRY[i].0 == size {
+            return Some(REGISTRY[i].1);
+        }
+        i += 1;
+    }
+    None
+}
@@ -5,7 +5,11 @@
 mod butterfly;
 #[macro_use]
 mod avx_optimization;
+#[macro_use]
+mod neon_optimization;
+mod codelet;
 
+use crate::array::Array;
 use crate::fft::{Fft, Transform};
 use crate::float::FftFloat;
 use crate::twiddle::compute_twiddle;
@@ -134,6 +138,133 @@ impl<T: FftFloat, Twiddles: Default + Extend<Complex<T>>, Work: Default + Extend
     }
 }
 
+/// A configuration for constructing autosort FFTs: the transform size and
+/// its resolved radix counts. [`codelet::lookup`] is tried first so common
+/// sizes skip the factorization loop; everything else falls back to the
+/// same greedy factorization [`Autosort::new`] uses.
+#[derive(Debug, Clone, Copy)]
+pub struct Configuration {
+    size: usize,
+    counts: [usize; NUM_RADICES],
+}
+
+impl Configuration {
+    /// Create a new configuration with the FFT size. Returns `None` if the
+    /// size cannot be factored into the supported radices.
+    pub const fn new(size: usize) -> Option<Self> {
+        if let Some(counts) = codelet::lookup(size) {
+            return Some(Self { size, counts });
+        }
+
+        let mut current_size = size;
+        let mut counts = [0usize; NUM_RADICES];
+        if current_size % RADICES[0] == 0 {
+            current_size /= RADICES[0];
+            counts[0] = 1;
+        }
+        let mut radix_index = 1;
+        while radix_index < NUM_RADICES {
+            while current_size % RADICES[radix_index] == 0 {
+                current_size /= RADICES[radix_index];
+                counts[radix_index] += 1;
+            }
+            radix_index += 1;
+        }
+        if current_size == 1 {
+            Some(Self { size, counts })
+        } else {
+            None
+        }
+    }
+
+    /// Return the FFT size.
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Return the radix counts.
+    pub const fn counts(&self) -> [usize; NUM_RADICES] {
+        self.counts
+    }
+
+    /// Return the number of twiddles an [`Autosort`] built from this
+    /// configuration requires, for sizing a [`crate::Array`]-backed twiddle
+    /// buffer up front.
+    pub const fn twiddles(&self) -> usize {
+        twiddle_len(self.size, self.counts)
+    }
+}
+
+/// The total number of twiddles [`initialize_twiddles_slice`] writes for
+/// `size` factored as `counts`: the sum of the transform size at each stage,
+/// not `size` itself (a multi-stage transform reuses a shrinking size's
+/// worth of twiddles at every stage).
+const fn twiddle_len(mut size: usize, counts: [usize; NUM_RADICES]) -> usize {
+    let mut total = 0;
+    let mut radix_index = 0;
+    while radix_index < NUM_RADICES {
+        let mut i = 0;
+        while i < counts[radix_index] {
+            total += size;
+            size /= RADICES[radix_index];
+            i += 1;
+        }
+        radix_index += 1;
+    }
+    total
+}
+
+/// Slice-based counterpart to [`initialize_twiddles`], for [`Array`]-backed
+/// twiddle buffers that are allocated up front at [`twiddle_len`] rather than
+/// built incrementally with `Extend`.
+fn initialize_twiddles_slice<T: FftFloat>(
+    mut size: usize,
+    counts: [usize; NUM_RADICES],
+    forward_twiddles: &mut [Complex<T>],
+    inverse_twiddles: &mut [Complex<T>],
+) {
+    let mut offset = 0;
+    for (radix, count) in RADICES.iter().zip(&counts) {
+        for _ in 0..*count {
+            let m = size / radix;
+            for i in 0..m {
+                forward_twiddles[offset] = Complex::<T>::one();
+                inverse_twiddles[offset] = Complex::<T>::one();
+                offset += 1;
+                for j in 1..*radix {
+                    forward_twiddles[offset] = compute_twiddle(i * j, size, true);
+                    inverse_twiddles[offset] = compute_twiddle(i * j, size, false);
+                    offset += 1;
+                }
+            }
+            size /= radix;
+        }
+    }
+}
+
+impl<T: FftFloat, Twiddles: Array<Complex<T>>, Work: Array<Complex<T>>> Autosort<T, Twiddles, Work> {
+    /// Create a new Stockham autosort generator from a configuration.
+    pub fn from_configuration(configuration: Configuration) -> Self {
+        let Configuration { size, counts } = configuration;
+        let mut forward_twiddles = Twiddles::new(twiddle_len(size, counts));
+        let mut inverse_twiddles = Twiddles::new(twiddle_len(size, counts));
+        initialize_twiddles_slice(
+            size,
+            counts,
+            forward_twiddles.as_mut(),
+            inverse_twiddles.as_mut(),
+        );
+        Self {
+            size,
+            counts,
+            forward_twiddles,
+            inverse_twiddles,
+            work: RefCell::new(Work::new(size)),
+            real_type: PhantomData,
+        }
+    }
+}
+
 macro_rules! implement {
     {
         $type:ty, $apply:ident
@@ -200,6 +331,13 @@ macro_rules! make_radix_fns {
                 }
             }
 
+            #[target_cfg(target = "aarch64+neon")]
+            {
+                if !$wide && crate::neon_optimization!($type, $radix, input, output, _forward, size, stride, cached_twiddles) {
+                    return
+                }
+            }
+
             let m = size / $radix;
 
             let (full_count, final_offset) = if $wide {
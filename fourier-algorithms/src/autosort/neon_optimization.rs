@@ -0,0 +1,168 @@
+#[cfg(target_arch = "aarch64")]
+use core::arch::aarch64::*;
+
+#[multiversion::target("aarch64+neon")]
+#[inline]
+unsafe fn cmul_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t {
+    // a, b each pack two complex f32 values as [re0, im0, re1, im1].
+    let b_re = vtrn1q_f32(b, b); // [br0, br0, br1, br1]
+    let b_im = vtrn2q_f32(b, b); // [bi0, bi0, bi1, bi1]
+    let a_swapped = vrev64q_f32(a); // [ai0, ar0, ai1, ar1]
+
+    // t0 = [ar0*br0, ai0*br0, ar1*br1, ai1*br1]
+    let t0 = vmulq_f32(a, b_re);
+    // t1 = [ai0*bi0, ar0*bi0, ai1*bi1, ar1*bi1]
+    let t1 = vmulq_f32(a_swapped, b_im);
+    // Negate the real-part lanes (0, 2) of t1 so the add below turns into a
+    // subtraction there and an addition at the imaginary-part lanes (1, 3).
+    let t1_negated = vnegq_f32(t1);
+    let sign_mask: uint32x4_t = core::mem::transmute([!0u32, 0, !0u32, 0]);
+    let signed_t1 = vbslq_f32(sign_mask, t1_negated, t1);
+    vaddq_f32(t0, signed_t1)
+}
+
+#[multiversion::target("aarch64+neon")]
+#[inline]
+unsafe fn cmul_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t {
+    // a, b each pack one complex f64 value as [re, im].
+    let b_re = vtrn1q_f64(b, b); // [br, br]
+    let b_im = vtrn2q_f64(b, b); // [bi, bi]
+    let a_swapped = vextq_f64(a, a, 1); // [ai, ar]
+
+    let t0 = vmulq_f64(a, b_re); // [ar*br, ai*br]
+    let t1 = vmulq_f64(a_swapped, b_im); // [ai*bi, ar*bi]
+    let t1_negated = vnegq_f64(t1);
+    // Keep the negated real-part lane (0) of t1, the original imaginary-part lane (1).
+    let signed_t1 = vcopyq_laneq_f64(t1_negated, 1, t1, 1);
+    vaddq_f64(t0, signed_t1)
+}
+
+#[multiversion::target("aarch64+neon")]
+#[inline]
+pub(crate) unsafe fn radix_4_stride_1_neon_f32(
+    input: &[num_complex::Complex<f32>],
+    output: &mut [num_complex::Complex<f32>],
+    size: usize,
+    stride: usize,
+    twiddles: &[num_complex::Complex<f32>],
+    forward: bool,
+) {
+    assert_eq!(stride, 1);
+    const RADIX: usize = 4;
+    let m = size / RADIX;
+
+    for i in 0..m {
+        // Gather the four inputs into two registers of two packed complex values
+        // each, mirroring the AVX kernel's single 256-bit gather. The four inputs
+        // are stride `m` apart, not contiguous, so each pair is assembled by hand.
+        let r0 = input.as_ptr().add(i).read();
+        let r1 = input.as_ptr().add(m + i).read();
+        let r2 = input.as_ptr().add(2 * m + i).read();
+        let r3 = input.as_ptr().add(3 * m + i).read();
+        let lo = vld1q_f32([r0.re, r0.im, r1.re, r1.im].as_ptr());
+        let hi = vld1q_f32([r2.re, r2.im, r3.re, r3.im].as_ptr());
+
+        // First radix-2 stage: elementwise across the two registers combines
+        // (r0, r2) at lane 0 and (r1, r3) at lane 1 of each.
+        let sum = vaddq_f32(lo, hi); // [a0 = r0+r2, a1 = r1+r3]
+        let diff = vsubq_f32(lo, hi); // [ar1 = r0-r2, ar3 = r1-r3]
+
+        // Rotate the second difference (lane 1 of `diff`) by +-i.
+        let diff_rev = vrev64q_f32(diff); // [im, re] within each lane pair
+        let diff_rotated = if forward {
+            // multiply by -i: (re, im) -> (im, -re)
+            vmulq_f32(diff_rev, vld1q_f32([1.0f32, -1.0, 1.0, -1.0].as_ptr()))
+        } else {
+            // multiply by i: (re, im) -> (-im, re)
+            vmulq_f32(diff_rev, vld1q_f32([-1.0f32, 1.0, -1.0, 1.0].as_ptr()))
+        };
+        let rotated_lane1: uint32x4_t = core::mem::transmute([0u32, 0, !0u32, !0u32]);
+        let diff_with_rotated_lane1 = vbslq_f32(rotated_lane1, diff_rotated, diff);
+
+        // Second radix-2 stage: horizontal combine within `sum` (lanes 0 and 1)
+        // and within `diff_with_rotated_lane1` (lanes 0 and 1).
+        let sum_swapped = vextq_f32(sum, sum, 2);
+        let b_sum_sum = vaddq_f32(sum, sum_swapped); // b0 at lane 0, duplicated at lane 2
+        let b_sum_diff = vsubq_f32(sum, sum_swapped); // b1 at lane 0, duplicated at lane 2
+
+        let d = diff_with_rotated_lane1;
+        let d_swapped = vextq_f32(d, d, 2);
+        let b_diff_sum = vaddq_f32(d, d_swapped);
+        let b_diff_diff = vsubq_f32(d, d_swapped);
+
+        let out0 = vcombine_f32(vget_low_f32(b_sum_sum), vget_low_f32(b_diff_sum));
+        let out1 = vcombine_f32(vget_low_f32(b_sum_diff), vget_low_f32(b_diff_diff));
+
+        let (out0, out1) = if size != RADIX {
+            let t0 = vld1q_f32(twiddles.as_ptr().add(RADIX * i) as *const f32);
+            let t1 = vld1q_f32(twiddles.as_ptr().add(RADIX * i + 2) as *const f32);
+            (cmul_f32(out0, t0), cmul_f32(out1, t1))
+        } else {
+            (out0, out1)
+        };
+
+        vst1q_f32(output.as_mut_ptr().add(RADIX * i) as *mut f32, out0);
+        vst1q_f32(output.as_mut_ptr().add(RADIX * i + 2) as *mut f32, out1);
+    }
+}
+
+#[multiversion::target("aarch64+neon")]
+#[inline]
+#[allow(dead_code)]
+pub(crate) unsafe fn radix_4_stride_1_neon_f64(
+    input: &[num_complex::Complex<f64>],
+    output: &mut [num_complex::Complex<f64>],
+    forward: bool,
+    size: usize,
+    twiddles: &[num_complex::Complex<f64>],
+) {
+    const RADIX: usize = 4;
+    let m = size / RADIX;
+
+    for i in 0..m {
+        let r0 = input.as_ptr().add(i).read();
+        let r1 = input.as_ptr().add(m + i).read();
+        let r2 = input.as_ptr().add(2 * m + i).read();
+        let r3 = input.as_ptr().add(3 * m + i).read();
+        let v0 = vld1q_f64([r0.re, r0.im].as_ptr());
+        let v1 = vld1q_f64([r1.re, r1.im].as_ptr());
+        let v2 = vld1q_f64([r2.re, r2.im].as_ptr());
+        let v3 = vld1q_f64([r3.re, r3.im].as_ptr());
+
+        // First radix-2 stage.
+        let a0 = vaddq_f64(v0, v2); // r0+r2
+        let a1 = vsubq_f64(v0, v2); // r0-r2
+        let a2 = vaddq_f64(v1, v3); // r1+r3
+        let a3 = vsubq_f64(v1, v3); // r1-r3
+
+        let a3_swapped = vextq_f64(a3, a3, 1); // [im, re]
+        let a3_rotated = if forward {
+            // multiply by -i: (re, im) -> (im, -re)
+            vmulq_f64(a3_swapped, vld1q_f64([1.0f64, -1.0].as_ptr()))
+        } else {
+            // multiply by i: (re, im) -> (-im, re)
+            vmulq_f64(a3_swapped, vld1q_f64([-1.0f64, 1.0].as_ptr()))
+        };
+
+        // Second radix-2 stage.
+        let b0 = vaddq_f64(a0, a2);
+        let b2 = vsubq_f64(a0, a2);
+        let b1 = vaddq_f64(a1, a3_rotated);
+        let b3 = vsubq_f64(a1, a3_rotated);
+
+        let (b0, b1, b2, b3) = if size != RADIX {
+            let t0 = vld1q_f64(twiddles.as_ptr().add(RADIX * i) as *const f64);
+            let t1 = vld1q_f64(twiddles.as_ptr().add(RADIX * i + 1) as *const f64);
+            let t2 = vld1q_f64(twiddles.as_ptr().add(RADIX * i + 2) as *const f64);
+            let t3 = vld1q_f64(twiddles.as_ptr().add(RADIX * i + 3) as *const f64);
+            (cmul_f64(b0, t0), cmul_f64(b1, t1), cmul_f64(b2, t2), cmul_f64(b3, t3))
+        } else {
+            (b0, b1, b2, b3)
+        };
+
+        vst1q_f64(output.as_mut_ptr().add(RADIX * i) as *mut f64, b0);
+        vst1q_f64(output.as_mut_ptr().add(RADIX * i + 1) as *mut f64, b1);
+        vst1q_f64(output.as_mut_ptr().add(RADIX * i + 2) as *mut f64, b2);
+        vst1q_f64(output.as_mut_ptr().add(RADIX * i + 3) as *mut f64, b3);
+    }
+}
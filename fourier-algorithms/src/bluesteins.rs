@@ -13,8 +13,17 @@ extern crate alloc;
 #[cfg(all(not(feature = "std"), feature = "alloc"))]
 use alloc::{boxed::Box, vec::Vec};
 
-fn compute_half_twiddle<T: Float>(index: f64, size: usize) -> Complex<T> {
-    let theta = index * core::f64::consts::PI / size as f64;
+// Reduces the chirp exponent `n^2 mod 2*size` using integer arithmetic before any
+// floating-point conversion, so the angle stays accurate even once `n^2` itself would
+// overflow an `f64`'s 53-bit mantissa for large `size`.
+fn chirp_residue(n: i64, size: usize) -> u64 {
+    let modulus = 2 * size as i128;
+    let residue = (n as i128 * n as i128).rem_euclid(modulus);
+    residue as u64
+}
+
+fn compute_half_twiddle<T: Float>(residue: u64, size: usize) -> Complex<T> {
+    let theta = residue as f64 * core::f64::consts::PI / size as f64;
     Complex::new(
         T::from_f64(theta.cos()).unwrap(),
         T::from_f64(-theta.sin()).unwrap(),
@@ -31,16 +40,16 @@ fn initialize_w_twiddles<T: Float, F: Fft<Real = T>>(
     assert_eq!(forward_twiddles.len(), fft.size());
     assert_eq!(inverse_twiddles.len(), fft.size());
     for i in 0..fft.size() {
-        if let Some(index) = {
+        if let Some(residue) = {
             if i < size {
-                Some((i as f64).powi(2))
+                Some(chirp_residue(i as i64, size))
             } else if i > fft.size() - size {
-                Some(((i as f64) - (fft.size() as f64)).powi(2))
+                Some(chirp_residue(i as i64 - fft.size() as i64, size))
             } else {
                 None
             }
         } {
-            let twiddle = compute_half_twiddle(index, size);
+            let twiddle = compute_half_twiddle(residue, size);
             forward_twiddles[i] = twiddle.conj();
             inverse_twiddles[i] = twiddle;
         } else {
@@ -61,7 +70,9 @@ fn initialize_x_twiddles<T: Float>(
     assert_eq!(forward_twiddles.len(), size);
     assert_eq!(inverse_twiddles.len(), size);
     for i in 0..size {
-        let twiddle = compute_half_twiddle(-(i as f64).powi(2), size);
+        // The exponent here is `-i^2`; `chirp_residue` squares its argument, so the
+        // sign is applied by conjugating the `+i^2` twiddle.
+        let twiddle = compute_half_twiddle(chirp_residue(i as i64, size), size).conj();
         forward_twiddles[i] = twiddle.conj();
         inverse_twiddles[i] = twiddle;
     }
@@ -266,3 +277,48 @@ fn apply<T: Float, F: Fft<Real = T>>(
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn chirp_residue_survives_past_f64_mantissa_precision() {
+        // `n` here is close to `size`, which is itself large enough that `n * n`
+        // (~1.15e18) overflows what an `f64` can represent exactly (its 53-bit
+        // mantissa tops out around 9e15): squaring in `f64` *before* reducing
+        // mod `2 * size`, as the old chirp computation did, rounds `n * n` to
+        // the nearest representable double first, so the residue -- and the
+        // chirp angle derived from it -- comes out wrong. `chirp_residue`
+        // avoids that by reducing in `i128` before converting to floating
+        // point at all.
+        let size = 1usize << 30;
+        let n = (size - 1) as i64;
+
+        let exact = chirp_residue(n, size);
+
+        let naive_residue = ((n as f64).powi(2).rem_euclid(2.0 * size as f64)) as u64;
+
+        assert_ne!(
+            naive_residue, exact,
+            "the old f64-squaring path should already disagree with the exact \
+             residue at this size -- otherwise this test isn't pinning down the \
+             precision bug the fix addresses"
+        );
+
+        let modulus = 2i128 * size as i128;
+        let expected = ((n as i128 * n as i128).rem_euclid(modulus)) as u64;
+        assert_eq!(exact, expected);
+    }
+
+    #[test]
+    fn compute_half_twiddle_matches_textbook_angle_for_a_small_residue() {
+        let size = 8;
+        let n = 3i64;
+        let twiddle: Complex<f32> = compute_half_twiddle(chirp_residue(n, size), size);
+
+        let theta = (n * n) as f64 * core::f64::consts::PI / size as f64;
+        let expected = Complex::new(theta.cos() as f32, -theta.sin() as f32);
+        assert!((twiddle - expected).norm() < 1e-6);
+    }
+}
@@ -0,0 +1,177 @@
+//! Arbitrary-modulus convolution, built on three fixed [`NttPlan`]s, plus a
+//! floating-point convolution built on the same complex FFT butterflies as
+//! [`crate::autosort`].
+use crate::fft::Fft;
+use crate::float::Float;
+use crate::ntt::{ModInt, NttPlan};
+use crate::HeapAlgorithm;
+use num_complex::Complex;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{vec, vec::Vec};
+
+/// Convolves two real sequences, via zero-padding both to the next power of two at
+/// least `a.len() + b.len() - 1`, a forward FFT, a pointwise product, and an inverse
+/// FFT -- the floating-point counterpart of [`ConvolveModPlanner`]'s exact integer path.
+pub fn convolve<T: Float>(a: &[T], b: &[T]) -> Vec<T>
+where
+    HeapAlgorithm<T>: Fft<Real = T>,
+{
+    let result_len = a.len() + b.len() - 1;
+    let size = result_len.next_power_of_two();
+    let fft = HeapAlgorithm::new(size);
+
+    let mut x: Vec<Complex<T>> = vec![Complex::new(T::zero(), T::zero()); size];
+    let mut y = x.clone();
+    for (x, &a) in x.iter_mut().zip(a) {
+        *x = Complex::new(a, T::zero());
+    }
+    for (y, &b) in y.iter_mut().zip(b) {
+        *y = Complex::new(b, T::zero());
+    }
+
+    fft.fft_in_place(&mut x);
+    fft.fft_in_place(&mut y);
+    for (x, y) in x.iter_mut().zip(y.iter()) {
+        *x *= y;
+    }
+    fft.ifft_in_place(&mut x);
+
+    x.into_iter().take(result_len).map(|c| c.re).collect()
+}
+
+/// Convolves `a` and `b` modulo an arbitrary `modulus`, building a one-shot
+/// [`ConvolveModPlanner`] sized exactly for this call. Prefer constructing a
+/// [`ConvolveModPlanner`] directly and reusing it when convolving many sequences of the
+/// same length, since it caches the three inner NTT plans across calls.
+pub fn convolve_mod(a: &[u64], b: &[u64], modulus: u64) -> Vec<u64> {
+    ConvolveModPlanner::new(a.len() + b.len() - 1).convolve_mod(a, b, modulus)
+}
+
+/// Three NTT-friendly primes of the form `c * 2^k + 1`, each supporting power-of-two
+/// transforms far larger than any convolution this planner is likely to be asked for.
+/// Their product is about `2^89`; whether that's enough to recover a given convolution
+/// exactly depends on the inputs' magnitude, not just their width as `u64`s -- see
+/// [`ConvolveModPlanner::convolve_mod`].
+const P1: u64 = 880803841;
+const P2: u64 = 897581057;
+const P3: u64 = 998244353;
+
+fn inv_mod(value: u64, modulus: u64) -> u64 {
+    let (mut old_r, mut r) = (value as i128, modulus as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        let new_r = old_r - quotient * r;
+        old_r = r;
+        r = new_r;
+        let new_s = old_s - quotient * s;
+        old_s = s;
+        s = new_s;
+    }
+    ((old_s % modulus as i128 + modulus as i128) % modulus as i128) as u64
+}
+
+fn convolve_prime<const P: u64>(ntt: &NttPlan<ModInt<P>>, size: usize, a: &[u64], b: &[u64]) -> Vec<u64> {
+    // Pad the result back out to `size` entries (rather than the true
+    // `a.len() + b.len() - 1`) so every prime's residues line up by index
+    // for the Garner reconstruction below.
+    let mut result = crate::ntt::convolve(ntt, a, b);
+    result.resize(size, 0);
+    result
+}
+
+/// Convolves sequences of arbitrary `u64` coefficients under an arbitrary `u64`
+/// modulus, by running the convolution three times under fixed NTT-friendly primes and
+/// reconstructing each output coefficient with Garner's algorithm (the standard
+/// three-prime CRT trick) before the final reduction.
+///
+/// This is exact as long as each true convolution coefficient -- the un-reduced sum of
+/// products of inputs, before any modular reduction -- is less than `P1 * P2 * P3`
+/// (about `2^89`), which CRT needs in order to recover it uniquely. That bound is on the
+/// *inputs'* magnitude, not on `u64` itself: two arbitrary `u64` values already multiply
+/// to up to `2^128`, so this only holds when the caller's inputs are small relative to
+/// their length, e.g. residues below some known `modulus` (a convolution of `n` terms
+/// each less than `modulus` has true coefficients less than `n * modulus^2`, which must
+/// itself be less than `P1 * P2 * P3` for [`convolve_mod`](Self::convolve_mod) or
+/// [`convolve_exact`](Self::convolve_exact) to be exact).
+///
+/// Caches the three inner transforms so repeated convolutions of the same (padded)
+/// size avoid replanning.
+pub struct ConvolveModPlanner {
+    size: usize,
+    ntt1: NttPlan<ModInt<P1>>,
+    ntt2: NttPlan<ModInt<P2>>,
+    ntt3: NttPlan<ModInt<P3>>,
+    inv_p1_mod_p2: u64,
+    inv_p1p2_mod_p3: u64,
+}
+
+impl ConvolveModPlanner {
+    /// Creates a planner able to convolve sequences whose result has up to
+    /// `max_result_len` coefficients.
+    pub fn new(max_result_len: usize) -> Self {
+        let size = max_result_len.next_power_of_two();
+        let p1p2_mod_p3 = (P1 as u128 * P2 as u128 % P3 as u128) as u64;
+        Self {
+            size,
+            ntt1: NttPlan::new(size).expect("size is a power of two"),
+            ntt2: NttPlan::new(size).expect("size is a power of two"),
+            ntt3: NttPlan::new(size).expect("size is a power of two"),
+            inv_p1_mod_p2: inv_mod(P1 % P2, P2),
+            inv_p1p2_mod_p3: inv_mod(p1p2_mod_p3, P3),
+        }
+    }
+
+    /// Computes `a (*) b`, reduced modulo `modulus`. The output has `a.len() + b.len() - 1`
+    /// coefficients.
+    ///
+    /// Exact when `a` and `b` are residues below `modulus` and, in addition, each true
+    /// coefficient (at most `n * modulus^2` for `n` terms) stays under the
+    /// `P1 * P2 * P3` CRT bound described on [`ConvolveModPlanner`]. Only the first
+    /// condition is debug-checked below; the CRT bound is not checked.
+    pub fn convolve_mod(&self, a: &[u64], b: &[u64], modulus: u64) -> Vec<u64> {
+        debug_assert!(
+            a.iter().chain(b.iter()).all(|&x| x < modulus),
+            "convolve_mod's inputs must already be residues below `modulus`"
+        );
+        self.convolve_exact(a, b)
+            .into_iter()
+            .map(|x| (x % modulus as u128) as u64)
+            .collect()
+    }
+
+    /// Computes `a (*) b` exactly, with no modular reduction. The output has
+    /// `a.len() + b.len() - 1` coefficients, each returned as the full `u128`
+    /// reconstructed by Garner's algorithm. This is only the *true* coefficient -- rather
+    /// than that value reduced mod `P1 * P2 * P3` -- when the inputs are small enough for
+    /// the bound on [`ConvolveModPlanner`] to hold; it's the caller's responsibility to
+    /// keep inputs within that bound, the same as for [`convolve_mod`](Self::convolve_mod).
+    /// Use this instead of `convolve_mod` when the caller wants the un-reduced product,
+    /// e.g. to reduce by a modulus not known up front.
+    pub fn convolve_exact(&self, a: &[u64], b: &[u64]) -> Vec<u128> {
+        let result_len = a.len() + b.len() - 1;
+        assert!(result_len <= self.size, "planner was built for a smaller result");
+
+        let r1 = convolve_prime(&self.ntt1, self.size, a, b);
+        let r2 = convolve_prime(&self.ntt2, self.size, a, b);
+        let r3 = convolve_prime(&self.ntt3, self.size, a, b);
+
+        let p1p2 = P1 as u128 * P2 as u128;
+        (0..result_len)
+            .map(|i| {
+                // Garner's algorithm: combine r1, r2 modulo p1 * p2, then fold in r3.
+                let x1 = r1[i] as u128;
+                let t2 = ((r2[i] + P2 - r1[i] % P2) % P2) as u128 * self.inv_p1_mod_p2 as u128 % P2 as u128;
+                let x12 = x1 + P1 as u128 * t2;
+                let t3 = ((r3[i] as u128 + P3 as u128 - x12 % P3 as u128) % P3 as u128)
+                    * self.inv_p1p2_mod_p3 as u128
+                    % P3 as u128;
+                x12 + p1p2 * t3
+            })
+            .collect()
+    }
+}
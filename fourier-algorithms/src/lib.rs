@@ -8,9 +8,16 @@ mod twiddle;
 mod array;
 pub mod autosort;
 pub mod bluesteins;
+pub mod convolve;
 mod fft;
 mod float;
 pub mod identity;
+pub mod mdct;
+pub mod ntt;
+pub mod real;
+pub mod split_mdct;
+pub mod split_radix;
+pub mod vector;
 
 pub use array::*;
 pub use fft::*;
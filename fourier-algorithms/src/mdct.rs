@@ -0,0 +1,111 @@
+//! Modified discrete cosine transform (and its inverse), built on top of a complex
+//! [`Fft`], for audio-codec-style time-frequency transforms.
+use crate::fft::Fft;
+use crate::float::Float;
+use crate::HeapAlgorithm;
+use num_complex::Complex;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{boxed::Box, vec, vec::Vec};
+
+/// An `N`-coefficient MDCT/IMDCT pair for a `2N`-sample window, built on top of a
+/// size-`4N` complex [`Fft`].
+///
+/// An MDCT of a windowed length-`2N` real input is computed by zero-padding the input
+/// to `4N`, running a single complex FFT of that size, and extracting the odd-indexed
+/// bins with a post-twiddle that accounts for the transform's half-sample phase
+/// offset. The IMDCT mirrors this with a pre-twiddle before the same size-`4N` FFT.
+/// [`imdct`](Mdct::imdct) applies the caller's synthesis window to the raw `2N`-sample
+/// result and overlap-adds it with the tail kept from the previous call, so it emits
+/// `N` samples of finished output per call -- the standard streaming IMDCT shape used
+/// by AAC/AC-3/Vorbis-style codecs.
+pub struct Mdct<T> {
+    size: usize,
+    fft: HeapAlgorithm<T>,
+    overlap: Box<[T]>,
+}
+
+impl<T: Float> Mdct<T> {
+    /// Creates an MDCT/IMDCT pair for `size` coefficients (a `2 * size` sample
+    /// window).
+    pub fn new(size: usize) -> Self {
+        Self {
+            size,
+            fft: HeapAlgorithm::new(4 * size),
+            overlap: vec![T::zero(); size].into_boxed_slice(),
+        }
+    }
+
+    /// The number of MDCT coefficients (half the window length).
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Computes the MDCT of `window * input` (`input` and `window` both length
+    /// `2 * size()`) into `output` (length `size()`).
+    pub fn mdct(&self, window: &[T], input: &[T], output: &mut [T]) {
+        let n = self.size;
+        assert_eq!(input.len(), 2 * n, "input must hold 2 * size() samples");
+        assert_eq!(window.len(), 2 * n, "window must hold 2 * size() samples");
+        assert_eq!(output.len(), n, "output must hold size() coefficients");
+
+        let mut padded: Box<[Complex<T>]> = vec![Complex::default(); 4 * n].into_boxed_slice();
+        for ((padded, &x), &w) in padded.iter_mut().zip(input.iter()).zip(window.iter()) {
+            *padded = Complex::new(x * w, T::zero());
+        }
+        self.fft.fft_in_place(&mut padded);
+
+        for k in 0..n {
+            let m = 2 * k + 1;
+            let angle = T::PI() * T::from_usize(m * (n + 1)).unwrap() / T::from_usize(4 * n).unwrap();
+            let twiddle = Complex::new(angle.cos(), -angle.sin());
+            output[k] = (twiddle * padded[m]).re;
+        }
+    }
+
+    /// Computes the raw (unwindowed) IMDCT of `input` (length `size()`) into `output`
+    /// (length `2 * size()`).
+    fn imdct_raw(&self, input: &[T], output: &mut [T]) {
+        let n = self.size;
+        let mut padded: Box<[Complex<T>]> = vec![Complex::default(); 4 * n].into_boxed_slice();
+        for k in 0..n {
+            let angle = T::PI() * T::from_usize(k).unwrap() / T::from_usize(2).unwrap();
+            let twiddle = Complex::new(angle.cos(), -angle.sin());
+            padded[k] = Complex::new(input[k], T::zero()) * twiddle;
+        }
+        self.fft.fft_in_place(&mut padded);
+
+        let scale = T::from_usize(2).unwrap() / T::from_usize(n).unwrap();
+        for (out_n, output) in output.iter_mut().enumerate() {
+            let m = 2 * out_n + 1;
+            let angle = T::PI() * T::from_usize(m + n).unwrap() / T::from_usize(4 * n).unwrap();
+            let twiddle = Complex::new(angle.cos(), -angle.sin());
+            *output = scale * (twiddle * padded[m]).re;
+        }
+    }
+
+    /// Computes the IMDCT of `input` (`size()` coefficients), applies the caller's
+    /// `window` (length `2 * size()`) to the raw `2 * size()`-sample result, and
+    /// overlap-adds it with the tail retained from the previous call. Writes
+    /// `size()` samples of finished output.
+    pub fn imdct(&mut self, window: &[T], input: &[T], output: &mut [T]) {
+        let n = self.size;
+        assert_eq!(input.len(), n, "input must hold size() coefficients");
+        assert_eq!(window.len(), 2 * n, "window must hold 2 * size() samples");
+        assert_eq!(output.len(), n, "output must hold size() finished samples");
+
+        let mut raw: Vec<T> = vec![T::zero(); 2 * n];
+        self.imdct_raw(input, &mut raw);
+        for (r, w) in raw.iter_mut().zip(window.iter()) {
+            *r = *r * *w;
+        }
+
+        for n_ in 0..n {
+            output[n_] = raw[n_] + self.overlap[n_];
+            self.overlap[n_] = raw[n + n_];
+        }
+    }
+}
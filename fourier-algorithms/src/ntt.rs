@@ -0,0 +1,405 @@
+//! A number-theoretic transform: the same mixed-radix 4/8/4/3/2 decomposition
+//! [`crate::autosort`] uses for `Complex<T>`, run over `Z/pZ` instead. This is exact (no
+//! floating-point rounding), which makes it suitable for integer polynomial
+//! multiplication -- see [`crate::convolve`] for the arbitrary-modulus wrapper built
+//! on top of it.
+use core::ops::{Add, Mul, Neg, Sub};
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::vec::Vec;
+
+const NUM_RADICES: usize = 5;
+const RADICES: [usize; NUM_RADICES] = [4, 8, 4, 3, 2];
+
+/// A commutative ring element usable as the scalar type of an [`NttPlan`], analogous to
+/// [`generic_simd::vector::scalar::Scalar`](../../generic_simd/vector/scalar/trait.Scalar.html)
+/// for the floating-point `Fft` impls.
+pub trait Ring:
+    Copy + Default + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    /// The multiplicative identity.
+    fn one() -> Self;
+
+    /// Embeds a `u64` into the ring.
+    fn from_u64(value: u64) -> Self;
+
+    /// The multiplicative inverse. Only required to be correct for nonzero elements.
+    fn inv(self) -> Self;
+
+    /// Returns `true` if the ring has a primitive `n`-th root of unity (so an
+    /// [`NttPlan`] of length `n` can be constructed).
+    fn has_root_of_unity(n: usize) -> bool;
+
+    /// A primitive `n`-th root of unity. Only called when [`has_root_of_unity`](Ring::has_root_of_unity) is `true`.
+    fn root_of_unity(n: usize) -> Self;
+}
+
+/// An integer modulo the prime `P`, stored in Montgomery form to keep the multiply in
+/// the stage loop cheap (no `u128` division).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ModInt<const P: u64>(u64);
+
+impl<const P: u64> ModInt<P> {
+    // -P^-1 mod 2^64, via Newton's iteration (doubles the number of correct bits
+    // each step, starting from the trivially-correct 1-bit inverse of an odd P).
+    const N_PRIME: u64 = {
+        let mut inv: u64 = 1;
+        let mut i = 0;
+        while i < 6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(P.wrapping_mul(inv)));
+            i += 1;
+        }
+        inv.wrapping_neg()
+    };
+
+    // R^2 mod P, used to move values into Montgomery form.
+    const R2: u64 = {
+        let r_mod_p = (1u128 << 64) % (P as u128);
+        ((r_mod_p * r_mod_p) % (P as u128)) as u64
+    };
+
+    #[inline]
+    fn redc(t: u128) -> u64 {
+        let m = (t as u64).wrapping_mul(Self::N_PRIME);
+        let u = (t + m as u128 * P as u128) >> 64;
+        if u >= P as u128 {
+            (u - P as u128) as u64
+        } else {
+            u as u64
+        }
+    }
+
+    pub fn new(value: u64) -> Self {
+        Self(Self::redc((value % P) as u128 * Self::R2 as u128))
+    }
+
+    /// Recovers the represented integer, in `0..P`.
+    pub fn value(self) -> u64 {
+        Self::redc(self.0 as u128)
+    }
+
+    fn pow(self, mut exponent: u64) -> Self {
+        let mut base = self;
+        let mut result = Self::one();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    fn prime_factors_of(mut n: u64) -> Vec<u64> {
+        let mut factors = Vec::new();
+        let mut p = 2;
+        while p * p <= n {
+            if n % p == 0 {
+                factors.push(p);
+                while n % p == 0 {
+                    n /= p;
+                }
+            }
+            p += 1;
+        }
+        if n > 1 {
+            factors.push(n);
+        }
+        factors
+    }
+
+    fn primitive_root() -> Self {
+        let factors = Self::prime_factors_of(P - 1);
+        'candidate: for g in 2..P {
+            let g = Self::new(g);
+            for &q in &factors {
+                if g.pow((P - 1) / q) == Self::one() {
+                    continue 'candidate;
+                }
+            }
+            return g;
+        }
+        unreachable!("P is prime, so a primitive root always exists")
+    }
+}
+
+impl<const P: u64> Default for ModInt<P> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<const P: u64> Add for ModInt<P> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let sum = self.0 + rhs.0;
+        Self(if sum >= P { sum - P } else { sum })
+    }
+}
+
+impl<const P: u64> Sub for ModInt<P> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self(if self.0 >= rhs.0 {
+            self.0 - rhs.0
+        } else {
+            self.0 + P - rhs.0
+        })
+    }
+}
+
+impl<const P: u64> Mul for ModInt<P> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self(Self::redc(self.0 as u128 * rhs.0 as u128))
+    }
+}
+
+impl<const P: u64> Neg for ModInt<P> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(if self.0 == 0 { 0 } else { P - self.0 })
+    }
+}
+
+impl<const P: u64> Ring for ModInt<P> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+
+    fn from_u64(value: u64) -> Self {
+        Self::new(value)
+    }
+
+    fn inv(self) -> Self {
+        // Fermat's little theorem: P is prime, so x^(P - 2) == x^-1 (mod P).
+        self.pow(P - 2)
+    }
+
+    fn has_root_of_unity(n: usize) -> bool {
+        counts_for(n).is_some() && (P - 1) % n as u64 == 0
+    }
+
+    fn root_of_unity(n: usize) -> Self {
+        assert!(Self::has_root_of_unity(n), "P - 1 must be divisible by n");
+        Self::primitive_root().pow((P - 1) / n as u64)
+    }
+}
+
+/// Factors `size` into the same 4/8/4/3/2 ladder [`crate::autosort`] uses, or `None` if
+/// `size` doesn't factor that way.
+fn counts_for(size: usize) -> Option<[usize; NUM_RADICES]> {
+    let mut current_size = size;
+    let mut counts = [0usize; NUM_RADICES];
+    if current_size % RADICES[0] == 0 {
+        current_size /= RADICES[0];
+        counts[0] = 1;
+    }
+    for (count, radix) in counts.iter_mut().zip(&RADICES).skip(1) {
+        while current_size % radix == 0 {
+            current_size /= radix;
+            *count += 1;
+        }
+    }
+    if current_size == 1 {
+        Some(counts)
+    } else {
+        None
+    }
+}
+
+fn field_pow<R: Ring>(mut base: R, mut exponent: usize) -> R {
+    let mut result = R::one();
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base;
+        }
+        base = base * base;
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Field-generic counterpart of [`crate::autosort`]'s `initialize_twiddles`: the
+/// twiddles are powers of a primitive `size`-th root of unity rather than
+/// `e^{-2*pi*i*k/size}`.
+fn initialize_twiddles<R: Ring>(mut size: usize, counts: [usize; NUM_RADICES]) -> (Vec<R>, Vec<R>) {
+    let (mut forward_twiddles, mut inverse_twiddles) = (Vec::new(), Vec::new());
+    for (radix, count) in RADICES.iter().zip(&counts) {
+        for _ in 0..*count {
+            let m = size / radix;
+            let root = R::root_of_unity(size);
+            let inverse_root = root.inv();
+            for i in 0..m {
+                let wi = field_pow(root, i);
+                let inverse_wi = field_pow(inverse_root, i);
+                forward_twiddles.push(R::one());
+                inverse_twiddles.push(R::one());
+                let mut forward = R::one();
+                let mut inverse = R::one();
+                for _ in 1..*radix {
+                    forward = forward * wi;
+                    inverse = inverse * inverse_wi;
+                    forward_twiddles.push(forward);
+                    inverse_twiddles.push(inverse);
+                }
+            }
+            size /= radix;
+        }
+    }
+    (forward_twiddles, inverse_twiddles)
+}
+
+/// The largest radix in [`RADICES`] -- the widest direct-DFT butterfly [`apply_stage`]
+/// ever needs to hold in a stack buffer.
+const MAX_RADIX: usize = 8;
+
+/// Applies one Stockham stage as a direct size-`radix` DFT butterfly. The fast
+/// `±i`-rotation decomposition [`crate::autosort::butterfly`] uses for radix 4/8 relies
+/// on a rotation a generic [`Ring`] has no notion of, so this falls back to an
+/// `O(radix^2)` direct sum, followed by the usual per-output twiddle multiply.
+fn apply_stage<R: Ring>(
+    input: &[R],
+    output: &mut [R],
+    size: usize,
+    stride: usize,
+    radix: usize,
+    twiddles: &[R],
+    forward: bool,
+) {
+    let m = size / radix;
+    let omega = if forward {
+        R::root_of_unity(radix)
+    } else {
+        R::root_of_unity(radix).inv()
+    };
+    for i in 0..m {
+        let twiddles = &twiddles[i * radix..i * radix + radix];
+        for j in 0..stride {
+            let mut scratch = [R::default(); MAX_RADIX];
+            for (k, scratch) in scratch.iter_mut().enumerate().take(radix) {
+                *scratch = input[j + stride * (i + k * m)];
+            }
+            for k2 in 0..radix {
+                let base = field_pow(omega, k2);
+                let mut sum = R::default();
+                let mut wk = R::one();
+                for scratch in scratch.iter().take(radix) {
+                    sum = sum + *scratch * wk;
+                    wk = wk * base;
+                }
+                output[j + stride * (radix * i + k2)] = if k2 == 0 { sum } else { sum * twiddles[k2] };
+            }
+        }
+    }
+}
+
+/// A number-theoretic transform plan: the integer analog of [`crate::autosort::Autosort`]
+/// over a [`Ring`] with a large enough root of unity. `P - 1` must be divisible by the
+/// transform length, which is validated once here at construction rather than on every
+/// call to [`forward`](NttPlan::forward)/[`inverse`](NttPlan::inverse).
+pub struct NttPlan<R> {
+    size: usize,
+    counts: [usize; NUM_RADICES],
+    forward_twiddles: Vec<R>,
+    inverse_twiddles: Vec<R>,
+    size_inv: R,
+}
+
+impl<R: Ring> NttPlan<R> {
+    /// Creates a plan for the given transform length, or `None` if `size` isn't
+    /// 2/3/4/8-smooth, or the ring has no primitive `size`-th root of unity.
+    pub fn new(size: usize) -> Option<Self> {
+        let counts = counts_for(size)?;
+        if !R::has_root_of_unity(size) {
+            return None;
+        }
+        let (forward_twiddles, inverse_twiddles) = initialize_twiddles::<R>(size, counts);
+        Some(Self {
+            size,
+            counts,
+            forward_twiddles,
+            inverse_twiddles,
+            size_inv: R::from_u64(size as u64).inv(),
+        })
+    }
+
+    /// The configured transform length.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    fn apply(&self, data: &mut [R], forward: bool) {
+        assert_eq!(data.len(), self.size);
+        let mut work: Vec<R> = vec![R::default(); self.size];
+
+        let mut twiddles: &[R] = if forward {
+            &self.forward_twiddles
+        } else {
+            &self.inverse_twiddles
+        };
+
+        let mut size = self.size;
+        let mut stride = 1;
+        let mut data_in_work = false;
+        for (radix, iterations) in RADICES.iter().zip(self.counts) {
+            for _ in 0..iterations {
+                let (from, to): (&mut _, &mut _) = if data_in_work {
+                    (work.as_mut_slice(), data)
+                } else {
+                    (data, work.as_mut_slice())
+                };
+                apply_stage(from, to, size, stride, *radix, twiddles, forward);
+                size /= radix;
+                stride *= radix;
+                twiddles = &twiddles[size * radix..];
+                data_in_work = !data_in_work;
+            }
+        }
+
+        if data_in_work {
+            data.copy_from_slice(&work);
+        }
+
+        if !forward {
+            for x in data.iter_mut() {
+                *x = *x * self.size_inv;
+            }
+        }
+    }
+
+    /// Applies the forward NTT in place.
+    pub fn forward(&self, data: &mut [R]) {
+        self.apply(data, true);
+    }
+
+    /// Applies the inverse NTT in place, scaling by `N^-1 mod P`.
+    pub fn inverse(&self, data: &mut [R]) {
+        self.apply(data, false);
+    }
+}
+
+/// Convolves `a` and `b` under a single NTT `plan`, via forward transform, pointwise
+/// multiply, and inverse transform -- exact as long as every true (unreduced)
+/// convolution coefficient fits under the plan's modulus. When a single modulus isn't
+/// large enough, run this across several primes and reconstruct with CRT instead (see
+/// [`crate::convolve::ConvolveModPlanner`], which does exactly that).
+pub fn convolve<const P: u64>(plan: &NttPlan<ModInt<P>>, a: &[u64], b: &[u64]) -> Vec<u64> {
+    let size = plan.size();
+    let result_len = a.len() + b.len() - 1;
+    assert!(result_len <= size, "plan is too small for this convolution");
+
+    let mut x: Vec<ModInt<P>> = (0..size).map(|i| ModInt::from_u64(*a.get(i).unwrap_or(&0))).collect();
+    let mut y: Vec<ModInt<P>> = (0..size).map(|i| ModInt::from_u64(*b.get(i).unwrap_or(&0))).collect();
+    plan.forward(&mut x);
+    plan.forward(&mut y);
+    for (x, y) in x.iter_mut().zip(y.iter()) {
+        *x = *x * *y;
+    }
+    plan.inverse(&mut x);
+    x.iter().take(result_len).map(|v| v.value()).collect()
+}
@@ -0,0 +1,132 @@
+//! Real-input FFT via Hermitian-symmetry packing.
+use crate::fft::{Fft, Transform};
+use crate::float::Float;
+use num_complex::Complex;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{boxed::Box, vec};
+
+fn post_twiddle<T: Float>(k: usize, size: usize) -> Complex<T> {
+    let theta = -core::f64::consts::PI * k as f64 / size as f64;
+    Complex::new(
+        T::from_f64(theta.cos()).unwrap(),
+        T::from_f64(theta.sin()).unwrap(),
+    )
+}
+
+// Multiplies by `-i`: `(a + bi) * -i = b - ai`.
+fn mul_neg_i<T: Float>(z: Complex<T>) -> Complex<T> {
+    Complex::new(z.im, -z.re)
+}
+
+/// A real-to-complex transform of `2 * size` real samples using a size-`N` complex
+/// [`Fft`], returning the `N + 1` non-redundant Hermitian coefficients.
+///
+/// Packing recipe: interleave the real input into `z[n] = x[2n] + i*x[2n+1]` (length
+/// `N`), run the inner complex FFT on `z`, then recombine with
+/// `X[k] = (Z[k] + conj(Z[N-k]))/2 - i*e^{-i*pi*k/N}*(Z[k] - conj(Z[N-k]))/2`
+/// for `k` in `0..=N`, with `k = 0` and `k = N` special-cased to the purely real DC and
+/// Nyquist terms. The inverse undoes this into the half-size complex IFFT.
+pub struct RealFft<Inner> {
+    inner: Inner,
+}
+
+impl<Inner> RealFft<Inner> {
+    /// Wraps a size-`N` complex FFT to transform `2 * N` real samples.
+    pub fn new(inner: Inner) -> Self {
+        Self { inner }
+    }
+}
+
+/// A heap-allocated [`RealFft`] that builds its own inner [`crate::HeapAlgorithm`].
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub type HeapRealFft<T> = RealFft<crate::HeapAlgorithm<T>>;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T> RealFft<crate::HeapAlgorithm<T>>
+where
+    T: Float,
+    crate::HeapAlgorithm<T>: Fft<Real = T>,
+{
+    /// Creates a [`HeapRealFft`] for `real_size` real samples (`real_size`
+    /// must be even), building its own size-`real_size / 2` complex
+    /// [`crate::HeapAlgorithm`] inner FFT so the caller doesn't have to.
+    pub fn with_size(real_size: usize) -> Self {
+        assert_eq!(real_size % 2, 0, "real_size must be even");
+        Self::new(crate::HeapAlgorithm::new(real_size / 2))
+    }
+}
+
+impl<Inner> RealFft<Inner>
+where
+    Inner: Fft,
+{
+    /// The number of real samples this transform operates on (`2 * size()`).
+    pub fn real_size(&self) -> usize {
+        2 * self.inner.size()
+    }
+
+    /// The number of complex Hermitian coefficients this transform produces
+    /// (`size() + 1`).
+    pub fn complex_size(&self) -> usize {
+        self.inner.size() + 1
+    }
+}
+
+impl<Inner> RealFft<Inner>
+where
+    Inner: Fft,
+    Inner::Real: Float,
+{
+    /// Computes the `complex_size()` non-redundant Hermitian coefficients of
+    /// `input`, a slice of `real_size()` real samples.
+    pub fn transform(&self, input: &[Inner::Real], output: &mut [Complex<Inner::Real>]) {
+        let size = self.inner.size();
+        assert_eq!(input.len(), 2 * size);
+        assert_eq!(output.len(), size + 1);
+
+        let mut z: Box<[Complex<Inner::Real>]> = (0..size)
+            .map(|n| Complex::new(input[2 * n], input[2 * n + 1]))
+            .collect();
+        self.inner.transform_in_place(&mut z, Transform::Fft);
+
+        let half = Inner::Real::from_f64(0.5).unwrap();
+        output[0] = Complex::new(z[0].re + z[0].im, Inner::Real::default());
+        output[size] = Complex::new(z[0].re - z[0].im, Inner::Real::default());
+        for k in 1..size {
+            let zk = z[k];
+            let z_conj_n_minus_k = z[size - k].conj();
+            let even = (zk + z_conj_n_minus_k) * half;
+            let odd = (zk - z_conj_n_minus_k) * half;
+            output[k] = even + mul_neg_i(odd) * post_twiddle(k, size);
+        }
+    }
+
+    /// Reconstructs `real_size()` real samples from `complex_size()` Hermitian
+    /// coefficients.
+    pub fn inverse_transform(&self, input: &[Complex<Inner::Real>], output: &mut [Inner::Real]) {
+        let size = self.inner.size();
+        assert_eq!(input.len(), size + 1);
+        assert_eq!(output.len(), 2 * size);
+
+        let half = Inner::Real::from_f64(0.5).unwrap();
+        let mut z: Box<[Complex<Inner::Real>]> = vec![Complex::default(); size].into_boxed_slice();
+        z[0] = Complex::new(input[0].re + input[size].re, input[0].re - input[size].re) * half;
+        for k in 1..size {
+            let xk = input[k];
+            let x_conj_n_minus_k = input[size - k].conj();
+            let even = (xk + x_conj_n_minus_k) * half;
+            let rotated_odd = (x_conj_n_minus_k - xk) * post_twiddle::<Inner::Real>(k, size).conj() * half;
+            let odd = mul_neg_i(rotated_odd);
+            z[k] = even + odd;
+        }
+        self.inner.transform_in_place(&mut z, Transform::Ifft);
+        for (n, zn) in z.iter().enumerate() {
+            output[2 * n] = zn.re;
+            output[2 * n + 1] = zn.im;
+        }
+    }
+}
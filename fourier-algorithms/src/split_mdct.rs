@@ -0,0 +1,197 @@
+//! MDCT/IMDCT built directly on [`crate::split_radix::SplitRadix`] (and its
+//! [`crate::split_radix::HeapSplitRadix`] heap-allocated form), restricted to
+//! power-of-two coefficient counts.
+//!
+//! An `N`-coefficient MDCT of a windowed `2N`-sample input is computed in two
+//! steps: first the `2N` windowed real samples are folded into `N` real values
+//! with no FFT at all (a free linear recombination that falls out of the
+//! transform's own symmetry -- see [`fold`]), then those `N` reals are put
+//! through a DCT-IV via a single size-`2N` complex FFT (pre-twiddle, FFT, post-
+//! twiddle, real part -- see [`dct_iv`]). That's half the FFT size (and
+//! roughly half the total work) of [`crate::mdct::Mdct`]'s size-`4N` reduction.
+//! The IMDCT mirrors this exactly, since a DCT-IV is its own inverse up to a
+//! `2/N` scale: unfolding (the transpose of [`fold`]) spreads the `N` raw
+//! DCT-IV outputs back into `2N` samples. As with [`crate::mdct::Mdct`],
+//! [`Imdct::imdct`] applies the synthesis window and does the
+//! time-domain-aliasing-cancellation overlap-add with the tail kept from the
+//! previous call.
+use crate::fft::{Fft, Transform};
+use crate::float::Float;
+use crate::split_radix::HeapSplitRadix;
+use num_complex::Complex;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{vec, vec::Vec};
+
+use core::cell::RefCell;
+
+/// Folds `2 * window.len()` windowed real samples into `output.len()` real
+/// values -- the transpose of this is [`unfold`].
+fn fold<T: Float>(window: &[T], input: &[T], output: &mut [T]) {
+    let n = output.len();
+    let half = n / 2;
+    for i in 0..half {
+        let (j1, j2) = (n + half - 1 - i, n + half + i);
+        output[i] = -(window[j1] * input[j1]) - (window[j2] * input[j2]);
+    }
+    for i in half..n {
+        let (j1, j2) = (i - half, n + half - 1 - i);
+        output[i] = window[j1] * input[j1] - window[j2] * input[j2];
+    }
+}
+
+/// Spreads `input.len()` real values back into `2 * input.len()` raw samples --
+/// the transpose of [`fold`].
+fn unfold<T: Float>(input: &[T], output: &mut [T]) {
+    let n = input.len();
+    let half = n / 2;
+    for i in 0..half {
+        output[i] = input[half + i];
+    }
+    for i in half..n {
+        output[i] = -input[half + (n - 1 - i)];
+    }
+    for i in n..n + half {
+        output[i] = -input[n + half - 1 - i];
+    }
+    for i in n + half..2 * n {
+        output[i] = -input[i - n - half];
+    }
+}
+
+/// Computes the DCT-IV of `input` (length `n`) into `output` (length `n`) via
+/// a single size-`2n` complex FFT: pre-twiddle by `exp(i*pi*k/(2n))`, run an
+/// unscaled inverse transform (a positive-exponent DFT, which is exactly the
+/// sum this reduction needs), then post-twiddle and take the real part of the
+/// first `n` bins. A DCT-IV is its own inverse up to a `2/n` scale, so this
+/// same function drives both [`Mdct::mdct`] and [`Imdct::imdct_raw`].
+fn dct_iv<T: Float>(fft: &HeapSplitRadix<T>, work: &mut [Complex<T>], input: &[T], output: &mut [T]) {
+    let n = input.len();
+    let two_n = T::from_usize(2 * n).unwrap();
+    for w in work.iter_mut() {
+        *w = Complex::default();
+    }
+    for (k, &x) in input.iter().enumerate() {
+        let angle = T::PI() * T::from_usize(k).unwrap() / two_n;
+        work[k] = Complex::new(x * angle.cos(), x * angle.sin());
+    }
+    fft.transform_in_place(work, Transform::UnscaledIfft);
+    let four_n = T::from_usize(4 * n).unwrap();
+    for (k, out) in output.iter_mut().enumerate() {
+        let angle = T::PI() * T::from_usize(2 * k + 1).unwrap() / four_n;
+        let twiddle = Complex::new(angle.cos(), angle.sin());
+        *out = (twiddle * work[k]).re;
+    }
+}
+
+/// An `N`-coefficient MDCT for `2N`-sample windows, built on a size-`2N`
+/// [`HeapSplitRadix`] (`N` must be a power of two). Owns its work buffers in
+/// [`RefCell`]s, mirroring [`crate::split_radix::SplitRadix`]'s own `work`
+/// field, so [`mdct`](Self::mdct) takes `&self`.
+pub struct Mdct<T: Float> {
+    size: usize,
+    fft: HeapSplitRadix<T>,
+    folded: RefCell<Vec<T>>,
+    work: RefCell<Vec<Complex<T>>>,
+}
+
+impl<T: Float> Mdct<T> {
+    /// Creates an MDCT for `size` coefficients (a `2 * size` sample window).
+    /// Returns `None` if `size` is not a power of two.
+    pub fn new(size: usize) -> Option<Self> {
+        Some(Self {
+            size,
+            fft: HeapSplitRadix::new(2 * size)?,
+            folded: RefCell::new(vec![T::zero(); size]),
+            work: RefCell::new(vec![Complex::default(); 2 * size]),
+        })
+    }
+
+    /// The number of MDCT coefficients (half the window length).
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Computes the MDCT of `window * input` (`input` and `window` both length
+    /// `2 * size()`) into `output` (length `size()`).
+    pub fn mdct(&self, window: &[T], input: &[T], output: &mut [T]) {
+        let n = self.size;
+        assert_eq!(input.len(), 2 * n, "input must hold 2 * size() samples");
+        assert_eq!(window.len(), 2 * n, "window must hold 2 * size() samples");
+        assert_eq!(output.len(), n, "output must hold size() coefficients");
+
+        let mut folded = self.folded.borrow_mut();
+        fold(window, input, &mut folded);
+        let mut work = self.work.borrow_mut();
+        dct_iv(&self.fft, &mut work, &folded, output);
+    }
+}
+
+/// The inverse of [`Mdct`]: raw (unwindowed) IMDCT plus windowing and overlap-
+/// add, for `size`-coefficient blocks.
+pub struct Imdct<T: Float> {
+    size: usize,
+    fft: HeapSplitRadix<T>,
+    scaled: RefCell<Vec<T>>,
+    raw: Vec<T>,
+    work: RefCell<Vec<Complex<T>>>,
+    overlap: Vec<T>,
+}
+
+impl<T: Float> Imdct<T> {
+    /// Creates an IMDCT for `size` coefficients (a `2 * size` sample window).
+    /// Returns `None` if `size` is not a power of two.
+    pub fn new(size: usize) -> Option<Self> {
+        Some(Self {
+            size,
+            fft: HeapSplitRadix::new(2 * size)?,
+            scaled: RefCell::new(vec![T::zero(); size]),
+            raw: vec![T::zero(); 2 * size],
+            work: RefCell::new(vec![Complex::default(); 2 * size]),
+            overlap: vec![T::zero(); size],
+        })
+    }
+
+    /// The number of IMDCT coefficients (half the window length).
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Computes the raw (unwindowed) IMDCT of `input` (length `size()`) into
+    /// `output` (length `2 * size()`).
+    fn imdct_raw(&self, input: &[T], output: &mut [T]) {
+        let n = self.size;
+        let mut scaled = self.scaled.borrow_mut();
+        let mut work = self.work.borrow_mut();
+        dct_iv(&self.fft, &mut work, input, &mut scaled);
+        let scale = T::from_usize(2).unwrap() / T::from_usize(n).unwrap();
+        for s in scaled.iter_mut() {
+            *s = *s * scale;
+        }
+        unfold(&scaled, output);
+    }
+
+    /// Computes the IMDCT of `input` (`size()` coefficients), applies the
+    /// caller's `window` (length `2 * size()`) to the raw `2 * size()`-sample
+    /// result, and overlap-adds it with the tail retained from the previous
+    /// call. Writes `size()` samples of finished output.
+    pub fn imdct(&mut self, window: &[T], input: &[T], output: &mut [T]) {
+        let n = self.size;
+        assert_eq!(input.len(), n, "input must hold size() coefficients");
+        assert_eq!(window.len(), 2 * n, "window must hold 2 * size() samples");
+        assert_eq!(output.len(), n, "output must hold size() finished samples");
+
+        self.imdct_raw(input, &mut self.raw);
+        for (r, w) in self.raw.iter_mut().zip(window.iter()) {
+            *r = *r * *w;
+        }
+
+        for i in 0..n {
+            output[i] = self.raw[i] + self.overlap[i];
+            self.overlap[i] = self.raw[n + i];
+        }
+    }
+}
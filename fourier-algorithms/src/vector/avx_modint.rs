@@ -0,0 +1,196 @@
+#![allow(unused_macros)]
+
+/// 8-lane (`__m256i`, 32-bit) Montgomery modular arithmetic, mirroring
+/// [`crate::avx_vector`]'s interface (`width!`/`zeroed!`/`broadcast!`/`add!`/`sub!`/
+/// `mul!`/`load_wide!`/`store_wide!`/`load_narrow!`/`store_narrow!`) so the same
+/// codelet-dispatch machinery that picks between a wide and narrow complex backend can
+/// pick between this and a scalar [`crate::ntt::ModInt`] path for the NTT butterflies.
+///
+/// Residues are held as `a * R mod p` with `R = 2^32`. This differs from
+/// [`crate::ntt::ModInt`]'s Montgomery representation, which uses `R = 2^64`, so
+/// lanes must be decoded before comparing with `ModInt` values. `$p` must be an odd prime less
+/// than `2^30` (true of every NTT-friendly prime this crate uses), so that `2 * p` --
+/// the largest value `add`/`mul` can produce before their final conditional subtract --
+/// still fits in a `u32` lane without touching the sign bit.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! avx_modint {
+    { $p:expr } => {
+        #[cfg(target_arch = "x86")]
+        use std::arch::x86::*;
+        #[cfg(target_arch = "x86_64")]
+        use std::arch::x86_64::*;
+
+        const MODINT_P: u32 = $p;
+
+        // -p^-1 mod 2^32, via Newton's iteration (doubles the number of correct bits
+        // each step, starting from the trivially-correct 1-bit inverse of an odd p).
+        const MODINT_N_PRIME: u32 = {
+            let mut inv: u32 = 1;
+            let mut i = 0;
+            while i < 5 {
+                inv = inv.wrapping_mul(2u32.wrapping_sub(MODINT_P.wrapping_mul(inv)));
+                i += 1;
+            }
+            inv.wrapping_neg()
+        };
+
+        macro_rules! width {
+            {} => { 8 }
+        }
+
+        macro_rules! zeroed {
+            {} => { unsafe { _mm256_setzero_si256() } }
+        }
+
+        macro_rules! broadcast {
+            { $x:expr } => { unsafe { _mm256_set1_epi32($x as i32) } }
+        }
+
+        macro_rules! add {
+            { $a:expr, $b:expr } => {
+                unsafe {
+                    let sum = _mm256_add_epi32($a, $b);
+                    let ge = _mm256_cmpgt_epi32(sum, _mm256_set1_epi32(MODINT_P as i32 - 1));
+                    _mm256_sub_epi32(sum, _mm256_and_si256(ge, _mm256_set1_epi32(MODINT_P as i32)))
+                }
+            }
+        }
+
+        macro_rules! sub {
+            { $a:expr, $b:expr } => {
+                unsafe {
+                    let diff = _mm256_sub_epi32($a, $b);
+                    let lt = _mm256_cmpgt_epi32(_mm256_setzero_si256(), diff);
+                    _mm256_add_epi32(diff, _mm256_and_si256(lt, _mm256_set1_epi32(MODINT_P as i32)))
+                }
+            }
+        }
+
+        macro_rules! mul {
+            { $a:expr, $b:expr } => {
+                unsafe {
+                    let p = _mm256_set1_epi32(MODINT_P as i32);
+                    let n_prime = _mm256_set1_epi32(MODINT_N_PRIME as i32);
+
+                    // CIOS Montgomery reduction, done on the even (0,2,4,6) and odd
+                    // (1,3,5,7) lanes separately, since `_mm256_mul_epu32` only
+                    // multiplies the low 32 bits of each 64-bit slot.
+                    macro_rules! redc {
+                        { $ta:expr, $tb:expr } => {
+                            {
+                                let t = _mm256_mul_epu32($ta, $tb);
+                                let m = _mm256_mul_epu32(t, n_prime);
+                                let t = _mm256_add_epi64(t, _mm256_mul_epu32(m, p));
+                                _mm256_srli_epi64(t, 32)
+                            }
+                        }
+                    }
+
+                    let even = redc!($a, $b);
+                    let odd = redc!(_mm256_srli_epi64($a, 32), _mm256_srli_epi64($b, 32));
+                    let combined = _mm256_blend_epi32(even, _mm256_slli_epi64(odd, 32), 0xaa);
+
+                    let ge = _mm256_cmpgt_epi32(combined, _mm256_set1_epi32(MODINT_P as i32 - 1));
+                    _mm256_sub_epi32(combined, _mm256_and_si256(ge, p))
+                }
+            }
+        }
+
+        macro_rules! load_wide {
+            { $from:expr } => { unsafe { _mm256_loadu_si256($from as *const __m256i) } }
+        }
+
+        macro_rules! store_wide {
+            { $z:expr, $to:expr } => { unsafe { _mm256_storeu_si256($to as *mut __m256i, $z) } }
+        }
+
+        macro_rules! load_narrow {
+            { $from:expr } => {
+                unsafe { _mm256_set_epi32(0, 0, 0, 0, 0, 0, 0, $from.read() as i32) }
+            }
+        }
+
+        macro_rules! store_narrow {
+            { $z:expr, $to:expr } => {
+                unsafe { $to.write(_mm256_extract_epi32($z, 0) as u32) }
+            }
+        }
+    }
+}
+
+// No codelet currently dispatches to `avx_modint!` -- the scalar `crate::ntt::ModInt`
+// path is the only NTT butterfly backend wired up today. This is scaffolding for a
+// follow-up that adds the AVX2 dispatch, same as `width!`/`zeroed!`/etc. above are
+// unused until that follow-up calls them.
+#[cfg(all(test, any(target_arch = "x86", target_arch = "x86_64")))]
+mod test {
+    use crate::ntt::ModInt;
+
+    const P32: u32 = 998244353;
+    const P64: u64 = 998244353;
+
+    avx_modint! { P32 }
+
+    // Mirrors `MODINT_N_PRIME`/`redc` above, but worked out independently against
+    // plain `u64` arithmetic, so encoding/decoding here doesn't just assume the
+    // macro's own reduction is correct.
+    fn to_montgomery(x: u32) -> u32 {
+        (((x as u64) << 32) % P64) as u32
+    }
+
+    fn from_montgomery(t: u32) -> u32 {
+        let m = t.wrapping_mul(MODINT_N_PRIME);
+        let u = ((t as u64) + m as u64 * P64) >> 32;
+        if u >= P64 {
+            (u - P64) as u32
+        } else {
+            u as u32
+        }
+    }
+
+    #[target_feature(enable = "avx2")]
+    unsafe fn lanes(a: [u32; 8], b: [u32; 8]) -> ([u32; 8], [u32; 8], [u32; 8]) {
+        let ma: Vec<u32> = a.iter().copied().map(to_montgomery).collect();
+        let mb: Vec<u32> = b.iter().copied().map(to_montgomery).collect();
+
+        let va = load_wide!(ma.as_ptr());
+        let vb = load_wide!(mb.as_ptr());
+
+        let mut sum = [0u32; 8];
+        let mut diff = [0u32; 8];
+        let mut prod = [0u32; 8];
+        store_wide!(add!(va, vb), sum.as_mut_ptr());
+        store_wide!(sub!(va, vb), diff.as_mut_ptr());
+        store_wide!(mul!(va, vb), prod.as_mut_ptr());
+
+        let decode = |lanes: [u32; 8]| -> [u32; 8] {
+            let mut out = [0u32; 8];
+            for (o, l) in out.iter_mut().zip(lanes.iter()) {
+                *o = from_montgomery(*l);
+            }
+            out
+        };
+        (decode(sum), decode(diff), decode(prod))
+    }
+
+    #[test]
+    fn avx_modint_matches_plain_modint_arithmetic() {
+        if !std::is_x86_feature_detected!("avx2") {
+            return;
+        }
+
+        let a: [u32; 8] = [0, 1, 2, P32 - 1, 12345, 998244352, 500000000, 7];
+        let b: [u32; 8] = [0, 1, P32 - 1, 1, 998244352, 12345, 400000000, 998244346];
+
+        let (sum, diff, prod) = unsafe { lanes(a, b) };
+
+        for i in 0..8 {
+            let x = ModInt::<P64>::new(a[i] as u64);
+            let y = ModInt::<P64>::new(b[i] as u64);
+            assert_eq!(sum[i] as u64, (x + y).value(), "add mismatch at lane {i}");
+            assert_eq!(diff[i] as u64, (x - y).value(), "sub mismatch at lane {i}");
+            assert_eq!(prod[i] as u64, (x * y).value(), "mul mismatch at lane {i}");
+        }
+    }
+}
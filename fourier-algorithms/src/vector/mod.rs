@@ -0,0 +1,13 @@
+//! Lane-based complex vector backends.
+//!
+//! [`generic`] defines the macro-scoped, width-1 fallback used throughout
+//! `autosort`. [`wide`] defines a composable [`wide::Vector`] trait and the
+//! [`wide::X2`] width-doubling wrapper, so a 128-, 256-, or 512-bit backend
+//! can be built by nesting `X2` around a narrower one instead of hand-writing
+//! each width's arithmetic separately. [`avx_modint`] is the integer-ring
+//! counterpart of the crate's complex `avx_vector!` macro, for the NTT butterflies.
+
+pub mod avx_modint;
+pub mod generic;
+pub mod portable;
+pub mod wide;
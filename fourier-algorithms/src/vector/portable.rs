@@ -0,0 +1,86 @@
+use super::wide::Vector;
+use num_complex::Complex;
+
+/// A portable `N`-wide complex vector backed by a plain `[Complex<T>; N]`
+/// array, so the radix kernels vectorize the same way on architectures
+/// without a hand-written SIMD backend (ARM NEON, WASM `simd128`, or no SIMD
+/// at all) as they do on `x86`/`x86_64` via [`super::wide::X2`]. The
+/// architecture-specific backends (real NEON/`v128` primitives) are a drop-in
+/// replacement behind the same [`Vector`] interface; this one has no
+/// intrinsics, so it compiles everywhere.
+#[derive(Copy, Clone)]
+pub struct Portable<T, const N: usize>([Complex<T>; N]);
+
+impl<T: Copy + num_traits::Float, const N: usize> Vector<T> for Portable<T, N> {
+    const WIDTH: usize = N;
+
+    #[inline(always)]
+    unsafe fn zero() -> Self {
+        Self([Complex::default(); N])
+    }
+
+    #[inline(always)]
+    unsafe fn broadcast(value: &Complex<T>) -> Self {
+        Self([*value; N])
+    }
+
+    #[inline(always)]
+    unsafe fn add(self, other: Self) -> Self {
+        let mut out = self;
+        for i in 0..N {
+            out.0[i] = self.0[i] + other.0[i];
+        }
+        out
+    }
+
+    #[inline(always)]
+    unsafe fn sub(self, other: Self) -> Self {
+        let mut out = self;
+        for i in 0..N {
+            out.0[i] = self.0[i] - other.0[i];
+        }
+        out
+    }
+
+    #[inline(always)]
+    unsafe fn mul(self, other: Self) -> Self {
+        let mut out = self;
+        for i in 0..N {
+            out.0[i] = self.0[i] * other.0[i];
+        }
+        out
+    }
+
+    #[inline(always)]
+    unsafe fn rotate(self, positive: bool) -> Self {
+        let mut out = self;
+        for i in 0..N {
+            out.0[i] = if positive {
+                Complex::new(-self.0[i].im, self.0[i].re)
+            } else {
+                Complex::new(self.0[i].im, -self.0[i].re)
+            };
+        }
+        out
+    }
+
+    #[inline(always)]
+    unsafe fn load(from: *const Complex<T>) -> Self {
+        let mut out = Self([Complex::default(); N]);
+        for i in 0..N {
+            out.0[i] = unsafe { *from.add(i) };
+        }
+        out
+    }
+
+    #[inline(always)]
+    unsafe fn store(self, to: *mut Complex<T>) {
+        for i in 0..N {
+            unsafe { *to.add(i) = self.0[i] };
+        }
+    }
+}
+
+/// The portable 4-wide backend used when no architecture-specific SIMD
+/// backend is available.
+pub type Portable4<T> = Portable<T, 4>;
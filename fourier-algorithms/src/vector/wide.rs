@@ -0,0 +1,135 @@
+use core::marker::PhantomData;
+use num_complex::Complex;
+
+/// The minimal complex-lane interface [`X2`] composes to double a vector's
+/// width. A real SIMD backend (AVX, NEON, `v128`) implements this directly
+/// for its narrowest native width; every wider backend is then just `X2`
+/// nested around the next-narrower one.
+pub trait Vector<T>: Copy {
+    /// The number of complex lanes held by one vector.
+    const WIDTH: usize;
+    unsafe fn zero() -> Self;
+    unsafe fn broadcast(value: &Complex<T>) -> Self;
+    unsafe fn add(self, other: Self) -> Self;
+    unsafe fn sub(self, other: Self) -> Self;
+    unsafe fn mul(self, other: Self) -> Self;
+    /// Multiplies by `i` (or `-i` when `positive` is false).
+    unsafe fn rotate(self, positive: bool) -> Self;
+    unsafe fn load(from: *const Complex<T>) -> Self;
+    unsafe fn store(self, to: *mut Complex<T>);
+}
+
+/// A single-lane vector, the base case `X2` bottoms out at.
+#[derive(Copy, Clone)]
+pub struct Scalar<T>(Complex<T>);
+
+impl<T: Copy + num_traits::Float> Vector<T> for Scalar<T> {
+    const WIDTH: usize = 1;
+
+    #[inline(always)]
+    unsafe fn zero() -> Self {
+        Self(Complex::default())
+    }
+
+    #[inline(always)]
+    unsafe fn broadcast(value: &Complex<T>) -> Self {
+        Self(*value)
+    }
+
+    #[inline(always)]
+    unsafe fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+
+    #[inline(always)]
+    unsafe fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0)
+    }
+
+    #[inline(always)]
+    unsafe fn mul(self, other: Self) -> Self {
+        Self(self.0 * other.0)
+    }
+
+    #[inline(always)]
+    unsafe fn rotate(self, positive: bool) -> Self {
+        Self(if positive {
+            Complex::new(-self.0.im, self.0.re)
+        } else {
+            Complex::new(self.0.im, -self.0.re)
+        })
+    }
+
+    #[inline(always)]
+    unsafe fn load(from: *const Complex<T>) -> Self {
+        unsafe { Self(*from) }
+    }
+
+    #[inline(always)]
+    unsafe fn store(self, to: *mut Complex<T>) {
+        unsafe { *to = self.0 }
+    }
+}
+
+/// Doubles the width of an inner [`Vector`] `V` by holding two of them side
+/// by side and forwarding every op elementwise to each half. This is the
+/// same "wide vectors built from narrower ones" technique used to get a
+/// 512-bit backend from two 256-bit halves, or a 256-bit backend from two
+/// 128-bit halves, without a separate hand-written implementation per width:
+/// `X2<X2<Scalar<f32>>>` is a 4-wide vector built from nothing but the
+/// scalar base case.
+#[derive(Copy, Clone)]
+pub struct X2<V>(pub [V; 2], PhantomData<V>);
+
+impl<V> X2<V> {
+    pub fn new(halves: [V; 2]) -> Self {
+        Self(halves, PhantomData)
+    }
+}
+
+impl<T, V: Vector<T>> Vector<T> for X2<V> {
+    const WIDTH: usize = V::WIDTH * 2;
+
+    #[inline(always)]
+    unsafe fn zero() -> Self {
+        unsafe { Self::new([V::zero(), V::zero()]) }
+    }
+
+    #[inline(always)]
+    unsafe fn broadcast(value: &Complex<T>) -> Self {
+        unsafe { Self::new([V::broadcast(value), V::broadcast(value)]) }
+    }
+
+    #[inline(always)]
+    unsafe fn add(self, other: Self) -> Self {
+        unsafe { Self::new([self.0[0].add(other.0[0]), self.0[1].add(other.0[1])]) }
+    }
+
+    #[inline(always)]
+    unsafe fn sub(self, other: Self) -> Self {
+        unsafe { Self::new([self.0[0].sub(other.0[0]), self.0[1].sub(other.0[1])]) }
+    }
+
+    #[inline(always)]
+    unsafe fn mul(self, other: Self) -> Self {
+        unsafe { Self::new([self.0[0].mul(other.0[0]), self.0[1].mul(other.0[1])]) }
+    }
+
+    #[inline(always)]
+    unsafe fn rotate(self, positive: bool) -> Self {
+        unsafe { Self::new([self.0[0].rotate(positive), self.0[1].rotate(positive)]) }
+    }
+
+    #[inline(always)]
+    unsafe fn load(from: *const Complex<T>) -> Self {
+        unsafe { Self::new([V::load(from), V::load(from.add(V::WIDTH))]) }
+    }
+
+    #[inline(always)]
+    unsafe fn store(self, to: *mut Complex<T>) {
+        unsafe {
+            self.0[0].store(to);
+            self.0[1].store(to.add(V::WIDTH));
+        }
+    }
+}
@@ -1,10 +1,9 @@
 mod butterfly;
+#[cfg(feature = "parallel")]
+mod pool;
 
 use crate::{scalar::Scalar, Fft, Transform};
-use core::{
-    cell::RefCell,
-    simd::{LaneCount, Simd, SupportedLaneCount},
-};
+use core::simd::{LaneCount, Simd, SupportedLaneCount};
 use num_complex as nc;
 use num_traits::{Float, FromPrimitive, One};
 use simd_complex::SimdComplex;
@@ -13,6 +12,18 @@ use simd_traits::{num::Signed, swizzle::Shuffle, Vector};
 const NUM_RADICES: usize = 5;
 const RADICES: [usize; NUM_RADICES] = [4, 8, 4, 3, 2];
 
+/// Below this many signals, [`Autosort::transform_batch_in_place`] just runs the
+/// serial loop -- handing a single signal to the pool would spend more time
+/// scheduling than transforming.
+#[cfg(feature = "parallel")]
+const MIN_PARALLEL_BATCH_SIGNALS: usize = 2;
+
+/// Below this per-stage size, [`step`] runs its block loop serially -- splitting
+/// a handful of blocks across the pool would spend more time scheduling than the
+/// blocks themselves take to transform.
+#[cfg(feature = "parallel")]
+const MIN_PARALLEL_STEP_SIZE: usize = 1 << 14;
+
 #[inline]
 fn compute_twiddle<T, const FORWARD: bool>(index: usize, size: usize) -> num_complex::Complex<T>
 where
@@ -53,12 +64,17 @@ fn initialize_twiddles<T: Scalar>(
 }
 
 /// Implements a mixed-radix Stockham autosort algorithm for multiples of 2 and 3.
+///
+/// Unlike [`crate::bluesteins::Bluesteins`], this plan holds no scratch buffer of
+/// its own -- every transform (including each signal of a
+/// [`transform_batch_in_place`](Self::transform_batch_in_place) batch) allocates
+/// its own, so the plan has no interior mutability and is `Sync`, letting several
+/// threads transform different signals through the same `&Autosort` at once.
 pub struct Autosort<T> {
     size: usize,
     counts: [usize; NUM_RADICES],
     forward_twiddles: Vec<nc::Complex<T>>,
     inverse_twiddles: Vec<nc::Complex<T>>,
-    work: RefCell<Vec<nc::Complex<T>>>,
 }
 
 impl<T: Scalar> Autosort<T> {
@@ -79,18 +95,57 @@ impl<T: Scalar> Autosort<T> {
         }
         if current_size == 1 {
             let (forward_twiddles, inverse_twiddles) = initialize_twiddles(size, counts);
-            let work = vec![Default::default(); size];
             Some(Self {
                 size,
                 counts,
                 forward_twiddles,
                 inverse_twiddles,
-                work: RefCell::new(work),
             })
         } else {
             None
         }
     }
+
+    /// Transforms every signal in `signals` in place. When built with the
+    /// `parallel` feature and given enough signals ([`MIN_PARALLEL_BATCH_SIGNALS`])
+    /// to be worth it, each signal is handed to [`pool::scoped_for_each`]'s shared
+    /// worker pool (and its own scratch buffer, per the [`Autosort`] doc comment)
+    /// rather than funneling the whole batch through one shared buffer; otherwise
+    /// the signals are simply transformed one after another.
+    pub fn transform_batch_in_place(&self, signals: &mut [&mut [nc::Complex<T>]], transform: Transform)
+    where
+        T: Sync,
+        T::Mask: PartialEq,
+        Simd<T, 1>: Vector<Scalar = T> + Signed,
+        Simd<T, 4>: Vector<Scalar = T> + Signed,
+        Simd<T, 8>: Vector<Scalar = T> + Signed,
+        Simd<T, 16>: Vector<Scalar = T> + Signed,
+    {
+        #[cfg(feature = "parallel")]
+        if signals.len() >= MIN_PARALLEL_BATCH_SIGNALS {
+            // `&mut [&mut [Complex<T>]]` already guarantees the signals are
+            // disjoint; capture each as a raw pointer + length so the closure
+            // below only needs `Fn`, not `FnMut`, to hand to the pool.
+            struct SendMutPtr<T>(*mut T);
+            unsafe impl<T> Send for SendMutPtr<T> {}
+            unsafe impl<T> Sync for SendMutPtr<T> {}
+
+            let signals: Vec<(SendMutPtr<nc::Complex<T>>, usize)> = signals
+                .iter_mut()
+                .map(|signal| (SendMutPtr(signal.as_mut_ptr()), signal.len()))
+                .collect();
+            pool::scoped_for_each(signals.len(), |i| {
+                let (ptr, len) = &signals[i];
+                let signal = unsafe { core::slice::from_raw_parts_mut(ptr.0, *len) };
+                self.transform_in_place(signal, transform);
+            });
+            return;
+        }
+
+        for signal in signals.iter_mut() {
+            self.transform_in_place(signal, transform);
+        }
+    }
 }
 
 impl<T> Fft for Autosort<T>
@@ -99,6 +154,8 @@ where
     T::Mask: PartialEq,
     Simd<T, 1>: Vector<Scalar = T> + Signed,
     Simd<T, 4>: Vector<Scalar = T> + Signed,
+    Simd<T, 8>: Vector<Scalar = T> + Signed,
+    Simd<T, 16>: Vector<Scalar = T> + Signed,
 {
     type Real = T;
 
@@ -107,8 +164,8 @@ where
     }
 
     fn transform_in_place(&self, input: &mut [nc::Complex<T>], transform: Transform) {
-        let mut work = self.work.borrow_mut();
-        apply_steps(&self, input, work.as_mut(), transform);
+        let mut work = vec![nc::Complex::default(); self.size];
+        apply_steps(self, input, &mut work, transform);
     }
 }
 
@@ -124,6 +181,7 @@ unsafe fn step<T, const LANES: usize, const RADIX: usize, const FORWARD: bool>(
     twiddles: &[nc::Complex<T>],
     size: usize,
     stride: usize,
+    i_range: core::ops::Range<usize>,
 ) where
     T: Scalar,
     Simd<T, LANES>: Vector<Scalar = T> + Signed + Shuffle,
@@ -134,12 +192,13 @@ unsafe fn step<T, const LANES: usize, const RADIX: usize, const FORWARD: bool>(
     debug_assert!(output.len() == size * stride);
     debug_assert!(twiddles.len() >= size);
     debug_assert!(stride >= LANES);
+    debug_assert!(i_range.end <= size / RADIX);
 
     // TODO AVX optimization
 
     let m = size / RADIX;
 
-    for i in 0..m {
+    for i in i_range {
         let twiddles = {
             let mut step_twiddles = [SimdComplex::<T, LANES>::default(); RADIX];
             for k in 1..RADIX {
@@ -206,6 +265,82 @@ unsafe fn step<T, const LANES: usize, const RADIX: usize, const FORWARD: bool>(
     }
 }
 
+/// Runs one [`step`] over every block (`i` in `0..size / RADIX`), splitting the
+/// blocks across [`pool::scoped_for_each`]'s shared worker pool when built with
+/// the `parallel` feature and `size` is at least [`MIN_PARALLEL_STEP_SIZE`];
+/// otherwise runs the whole block range in one serial call, identically to
+/// before this existed.
+///
+/// Splitting by block is safe to parallelize: distinct `i` always read and
+/// write disjoint regions of `input`/`output` (see the index arithmetic in
+/// [`step`]), so chunks of the range can run concurrently without
+/// synchronizing anything beyond this function's own `scoped_for_each` call.
+#[inline(always)]
+unsafe fn dispatch_step<T, const LANES: usize, const RADIX: usize, const FORWARD: bool>(
+    input: &[nc::Complex<T>],
+    output: &mut [nc::Complex<T>],
+    twiddles: &[nc::Complex<T>],
+    size: usize,
+    stride: usize,
+) where
+    T: Scalar,
+    Simd<T, LANES>: Vector<Scalar = T> + Signed + Shuffle,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let m = size / RADIX;
+
+    #[cfg(feature = "parallel")]
+    if size >= MIN_PARALLEL_STEP_SIZE && m > 1 {
+        struct SendPtr<T>(*const T);
+        unsafe impl<T> Send for SendPtr<T> {}
+        unsafe impl<T> Sync for SendPtr<T> {}
+        struct SendMutPtr<T>(*mut T);
+        unsafe impl<T> Send for SendMutPtr<T> {}
+        unsafe impl<T> Sync for SendMutPtr<T> {}
+
+        let input_len = input.len();
+        let output_len = output.len();
+        let input_ptr = SendPtr(input.as_ptr());
+        let output_ptr = SendMutPtr(output.as_mut_ptr());
+        let twiddles_ptr = SendPtr(twiddles.as_ptr());
+        let twiddles_len = twiddles.len();
+
+        let workers = pool::worker_count().min(m);
+        let chunk = (m + workers - 1) / workers;
+
+        pool::scoped_for_each(workers, |w| {
+            let start = w * chunk;
+            let end = (start + chunk).min(m);
+            if start >= end {
+                return;
+            }
+            // SAFETY: every worker reconstructs the same full `input`/`output`/
+            // `twiddles` slices, but `step` only ever touches the disjoint
+            // region its own `i_range` owns.
+            let input = unsafe { core::slice::from_raw_parts(input_ptr.0, input_len) };
+            let output = unsafe { core::slice::from_raw_parts_mut(output_ptr.0, output_len) };
+            let twiddles = unsafe { core::slice::from_raw_parts(twiddles_ptr.0, twiddles_len) };
+            unsafe {
+                step::<T, LANES, RADIX, FORWARD>(input, output, twiddles, size, stride, start..end)
+            };
+        });
+        return;
+    }
+
+    step::<T, LANES, RADIX, FORWARD>(input, output, twiddles, size, stride, 0..m)
+}
+
+/// Picks the widest lane count this call site can actually use, then runs
+/// the whole mixed-radix pass at that width. Choosing the width at runtime
+/// (rather than baking in a single constant) lets the same binary use wide
+/// vectors on a machine that has them and fall back cleanly on one that
+/// doesn't: [`core::simd::Simd<T, LANES>`] lowers to native instructions
+/// when `LANES` matches (or divides) the target's vector width, and
+/// otherwise composes that width out of `LANES / width` narrower native
+/// vectors -- the "x2-over-W" technique -- so picking a too-wide `LANES` is
+/// never incorrect, only occasionally a missed optimization, which is why
+/// it's safe to probe features from widest to narrowest and stop at the
+/// first match.
 fn apply_steps<T>(
     autosort: &Autosort<T>,
     input: &mut [nc::Complex<T>],
@@ -216,6 +351,32 @@ fn apply_steps<T>(
     T::Mask: PartialEq,
     Simd<T, 1>: Vector<Scalar = T> + Signed,
     Simd<T, 4>: Vector<Scalar = T> + Signed,
+    Simd<T, 8>: Vector<Scalar = T> + Signed,
+    Simd<T, 16>: Vector<Scalar = T> + Signed,
+{
+    #[cfg(target_arch = "x86_64")]
+    {
+        if std::is_x86_feature_detected!("avx512f") {
+            return apply_steps_with_lanes::<T, 16>(autosort, input, output, transform);
+        }
+        if std::is_x86_feature_detected!("avx2") || std::is_x86_feature_detected!("avx") {
+            return apply_steps_with_lanes::<T, 8>(autosort, input, output, transform);
+        }
+    }
+    apply_steps_with_lanes::<T, 4>(autosort, input, output, transform)
+}
+
+fn apply_steps_with_lanes<T, const LANES: usize>(
+    autosort: &Autosort<T>,
+    input: &mut [nc::Complex<T>],
+    output: &mut [nc::Complex<T>],
+    transform: Transform,
+) where
+    T: Scalar,
+    T::Mask: PartialEq,
+    Simd<T, 1>: Vector<Scalar = T> + Signed,
+    Simd<T, LANES>: Vector<Scalar = T> + Signed,
+    LaneCount<LANES>: SupportedLaneCount,
 {
     assert_eq!(input.len(), autosort.size);
     assert_eq!(output.len(), autosort.size);
@@ -226,8 +387,6 @@ fn apply_steps<T>(
         autosort.inverse_twiddles.as_ref()
     };
 
-    const LANES: usize = 4; // FIXME use the preferred width for the target
-
     fn pick_step<T, const LANES: usize>(
         from: &mut [nc::Complex<T>],
         to: &mut [nc::Complex<T>],
@@ -245,18 +404,18 @@ fn apply_steps<T>(
         unsafe {
             if forward {
                 match radix {
-                    8 => step::<T, LANES, 8, true>(from, to, twiddles, size, stride),
-                    4 => step::<T, LANES, 4, true>(from, to, twiddles, size, stride),
-                    3 => step::<T, LANES, 3, true>(from, to, twiddles, size, stride),
-                    2 => step::<T, LANES, 2, true>(from, to, twiddles, size, stride),
+                    8 => dispatch_step::<T, LANES, 8, true>(from, to, twiddles, size, stride),
+                    4 => dispatch_step::<T, LANES, 4, true>(from, to, twiddles, size, stride),
+                    3 => dispatch_step::<T, LANES, 3, true>(from, to, twiddles, size, stride),
+                    2 => dispatch_step::<T, LANES, 2, true>(from, to, twiddles, size, stride),
                     _ => unimplemented!("unsupported radix"),
                 }
             } else {
                 match radix {
-                    8 => step::<T, LANES, 8, false>(from, to, twiddles, size, stride),
-                    4 => step::<T, LANES, 4, false>(from, to, twiddles, size, stride),
-                    3 => step::<T, LANES, 3, false>(from, to, twiddles, size, stride),
-                    2 => step::<T, LANES, 2, false>(from, to, twiddles, size, stride),
+                    8 => dispatch_step::<T, LANES, 8, false>(from, to, twiddles, size, stride),
+                    4 => dispatch_step::<T, LANES, 4, false>(from, to, twiddles, size, stride),
+                    3 => dispatch_step::<T, LANES, 3, false>(from, to, twiddles, size, stride),
+                    2 => dispatch_step::<T, LANES, 2, false>(from, to, twiddles, size, stride),
                     _ => unimplemented!("unsupported radix"),
                 }
             }
@@ -0,0 +1,106 @@
+//! A small persistent worker-thread pool backing [`super::Autosort`]'s `parallel`
+//! feature, so repeated transforms reuse a fixed set of threads instead of
+//! spawning and joining fresh OS threads on every call.
+
+use std::sync::mpsc;
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+use std::thread;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+struct Pool {
+    sender: mpsc::Sender<Job>,
+}
+
+impl Pool {
+    fn new(workers: usize) -> Self {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        for _ in 0..workers.max(1) {
+            let receiver = Arc::clone(&receiver);
+            thread::spawn(move || loop {
+                let job = {
+                    let receiver = receiver.lock().expect("pool worker mutex poisoned");
+                    receiver.recv()
+                };
+                match job {
+                    Ok(job) => job(),
+                    Err(_) => break,
+                }
+            });
+        }
+        Self { sender }
+    }
+}
+
+fn pool() -> &'static Pool {
+    static POOL: OnceLock<Pool> = OnceLock::new();
+    POOL.get_or_init(|| Pool::new(worker_count()))
+}
+
+/// How many workers the shared pool runs, used both to size the pool itself and
+/// to decide how many chunks to split a parallel task into.
+pub(crate) fn worker_count() -> usize {
+    thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
+/// Runs `f(index)` for every `index in 0..count` on the shared worker pool,
+/// blocking until all of them finish.
+///
+/// # Safety (not `unsafe fn`, but relies on a non-obvious invariant)
+/// `f` is only `Sync` for the body of this call, not `'static` -- the pool's jobs
+/// are `'static` because its threads outlive any single call, so each job is
+/// handed an `Arc` around `f` and a lifetime-erased view of it, and this
+/// function does not return until every job has observably finished (tracked by
+/// `remaining`/`done` below). That rendezvous is what makes erasing the
+/// lifetime sound: nothing can touch `f`'s captures after this call returns.
+pub(crate) fn scoped_for_each<F>(count: usize, f: F)
+where
+    F: Fn(usize) + Sync + Send,
+{
+    if count == 0 {
+        return;
+    }
+
+    struct Shared<F> {
+        f: F,
+        remaining: Mutex<usize>,
+        done: Condvar,
+    }
+    let shared = Arc::new(Shared {
+        f,
+        remaining: Mutex::new(count),
+        done: Condvar::new(),
+    });
+
+    let pool = pool();
+    for index in 0..count {
+        let shared = Arc::clone(&shared);
+        let job: Box<dyn FnOnce() + Send + '_> = Box::new(move || {
+            (shared.f)(index);
+            let mut remaining = shared.remaining.lock().expect("pool job mutex poisoned");
+            *remaining -= 1;
+            if *remaining == 0 {
+                shared.done.notify_one();
+            }
+        });
+        // SAFETY: see the function-level comment -- this call blocks until every
+        // job's `Arc<Shared<F>>` has been dropped, so the erased lifetime never
+        // outlives the real one.
+        let job: Job = unsafe { core::mem::transmute::<Box<dyn FnOnce() + Send + '_>, Job>(job) };
+        pool.sender.send(job).expect("pool workers never exit early");
+    }
+
+    let mut remaining = shared.remaining.lock().expect("pool job mutex poisoned");
+    while *remaining > 0 {
+        remaining = shared.done.wait(remaining).expect("pool job mutex poisoned");
+    }
+    drop(remaining);
+    // Each job still holds its `Arc` clone for a moment after decrementing
+    // `remaining`; wait for those clones to go so `f` is dropped here.
+    while Arc::strong_count(&shared) > 1 {
+        thread::yield_now();
+    }
+}
@@ -0,0 +1,149 @@
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// The algebraic structure the mixed-radix Stockham stages actually need:
+/// addition, subtraction, multiplication, negation, and a primitive `n`-th
+/// root of unity. [`crate::autosort::Autosort`] is built directly on
+/// `Complex<T>`, but the same radix-4/8/4/3/2 decomposition works over any
+/// `Field`, which is what lets [`crate::ntt::Ntt`] reuse it for an exact,
+/// integer number-theoretic transform instead of a floating-point one.
+pub trait Field:
+    Copy + Default + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    /// The multiplicative identity.
+    fn one() -> Self;
+
+    /// Embeds a `usize` into the field.
+    fn from_usize(value: usize) -> Self;
+
+    /// The multiplicative inverse, needed for inverse-transform scaling and
+    /// to turn a forward root of unity into its inverse.
+    fn inv(self) -> Self;
+
+    /// A primitive `n`-th root of unity, i.e. `root_of_unity(n).pow(n) == one()`
+    /// and no smaller positive power equals `one()`.
+    fn root_of_unity(n: usize) -> Self;
+}
+
+/// An element of `Z/PZ` for prime `P`, used to run an exact integer
+/// number-theoretic transform via [`crate::ntt::Ntt`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ModInt<const P: u64>(u64);
+
+impl<const P: u64> ModInt<P> {
+    /// Creates a field element from an integer, reducing it modulo `P`.
+    pub fn new(value: u64) -> Self {
+        Self(value % P)
+    }
+
+    /// Recovers the represented integer, in `0..P`.
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    fn pow(self, mut exponent: u64) -> Self {
+        let mut base = self;
+        let mut result = Self(1 % P);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// Factors `P - 1` and returns the smallest `g` such that
+    /// `g^((P - 1) / q) != 1 (mod P)` for every prime factor `q` of `P - 1`,
+    /// i.e. a primitive root of `P`.
+    fn primitive_root() -> Self {
+        let mut remaining = P - 1;
+        let mut prime_factors = Vec::new();
+        let mut q = 2u64;
+        while q * q <= remaining {
+            if remaining % q == 0 {
+                prime_factors.push(q);
+                while remaining % q == 0 {
+                    remaining /= q;
+                }
+            }
+            q += 1;
+        }
+        if remaining > 1 {
+            prime_factors.push(remaining);
+        }
+
+        (2..P)
+            .map(Self::new)
+            .find(|&candidate| {
+                prime_factors
+                    .iter()
+                    .all(|&q| candidate.pow((P - 1) / q).value() != 1)
+            })
+            .expect("P is not prime, or has no primitive root")
+    }
+}
+
+impl<const P: u64> Default for ModInt<P> {
+    fn default() -> Self {
+        Self(0)
+    }
+}
+
+impl<const P: u64> Add for ModInt<P> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let sum = self.0 + rhs.0;
+        Self(if sum >= P { sum - P } else { sum })
+    }
+}
+
+impl<const P: u64> Sub for ModInt<P> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self(if self.0 >= rhs.0 {
+            self.0 - rhs.0
+        } else {
+            self.0 + P - rhs.0
+        })
+    }
+}
+
+impl<const P: u64> Mul for ModInt<P> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self(((self.0 as u128 * rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Neg for ModInt<P> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(if self.0 == 0 { 0 } else { P - self.0 })
+    }
+}
+
+impl<const P: u64> Field for ModInt<P> {
+    fn one() -> Self {
+        Self(1 % P)
+    }
+
+    fn from_usize(value: usize) -> Self {
+        Self::new(value as u64)
+    }
+
+    fn inv(self) -> Self {
+        // P is prime, so Fermat's little theorem gives the inverse directly.
+        self.pow(P - 2)
+    }
+
+    fn root_of_unity(n: usize) -> Self {
+        assert_eq!((P - 1) % n as u64, 0, "n must divide P - 1");
+        Self::primitive_root().pow((P - 1) / n as u64)
+    }
+}
+
+/// The usual NTT-friendly prime `998244353 = 119 * 2^23 + 1` with primitive
+/// root `3`, supporting power-of-two transform lengths up to `2^23`. `3` does
+/// not divide `P - 1`, so lengths with a factor of 3 have no root of unity.
+pub type Mod998244353 = ModInt<998244353>;
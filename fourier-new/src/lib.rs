@@ -2,9 +2,18 @@
 
 mod autosort;
 mod bluesteins;
+mod field;
+mod ntt;
+mod raders;
+mod real;
 mod scalar;
 use num_complex as nc;
 
+pub use field::{Field, Mod998244353, ModInt};
+pub use ntt::Ntt;
+pub use raders::Raders;
+pub use real::RealFft;
+
 /// The interface for performing FFTs.
 pub trait Fft {
     /// The real type used by the FFT.
@@ -109,6 +118,8 @@ macro_rules! impl_real {
             fn new_complex(size: usize) -> Box<dyn Fft<Real = Self>> {
                 if let Some(autosort) = autosort::Autosort::new(size) {
                     Box::new(autosort)
+                } else if let Some(raders) = raders::Raders::new(size) {
+                    Box::new(raders)
                 } else {
                     Box::new(bluesteins::Bluesteins::new(size))
                 }
@@ -0,0 +1,201 @@
+use crate::field::Field;
+use std::cell::RefCell;
+
+const NUM_RADICES: usize = 5;
+const RADICES: [usize; NUM_RADICES] = [4, 8, 4, 3, 2];
+
+/// The largest radix in [`RADICES`] -- the widest direct-DFT butterfly
+/// [`apply_stage`] ever needs to hold in a stack buffer.
+const MAX_RADIX: usize = 8;
+
+fn field_pow<F: Field>(mut base: F, mut exponent: usize) -> F {
+    let mut result = F::one();
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base;
+        }
+        base = base * base;
+        exponent >>= 1;
+    }
+    result
+}
+
+fn counts_for(size: usize) -> Option<[usize; NUM_RADICES]> {
+    let mut current_size = size;
+    let mut counts = [0usize; NUM_RADICES];
+    if current_size % RADICES[0] == 0 {
+        current_size /= RADICES[0];
+        counts[0] = 1;
+    }
+    for (count, radix) in counts.iter_mut().zip(&RADICES).skip(1) {
+        while current_size % radix == 0 {
+            current_size /= radix;
+            *count += 1;
+        }
+    }
+    if current_size == 1 {
+        Some(counts)
+    } else {
+        None
+    }
+}
+
+/// Field-generic counterpart of [`crate::autosort::initialize_twiddles`]: the
+/// twiddles are powers of a primitive `size`-th root of unity rather than
+/// `e^{-2*pi*i*k/size}`.
+fn initialize_twiddles<F: Field>(
+    mut size: usize,
+    counts: [usize; NUM_RADICES],
+) -> (Vec<F>, Vec<F>) {
+    let (mut forward_twiddles, mut inverse_twiddles) = (Vec::new(), Vec::new());
+    for (radix, count) in RADICES.iter().zip(&counts) {
+        for _ in 0..*count {
+            let m = size / radix;
+            let root = F::root_of_unity(size);
+            let inverse_root = root.inv();
+            for i in 0..m {
+                let wi = field_pow(root, i);
+                let inverse_wi = field_pow(inverse_root, i);
+                forward_twiddles.push(F::one());
+                inverse_twiddles.push(F::one());
+                let mut forward = F::one();
+                let mut inverse = F::one();
+                for _ in 1..*radix {
+                    forward = forward * wi;
+                    inverse = inverse * inverse_wi;
+                    forward_twiddles.push(forward);
+                    inverse_twiddles.push(inverse);
+                }
+            }
+            size /= radix;
+        }
+    }
+    (forward_twiddles, inverse_twiddles)
+}
+
+/// Applies one Stockham stage as a direct size-`radix` DFT butterfly. There
+/// is no field-generic equivalent of [`crate::autosort::butterfly`]'s fast
+/// radix-4/8 decomposition, since that relies on a `±i` rotation a generic
+/// `Field` has no notion of -- so this falls back to an `O(radix^2)` direct
+/// sum, followed by the usual per-output twiddle multiply.
+fn apply_stage<F: Field>(
+    input: &[F],
+    output: &mut [F],
+    size: usize,
+    stride: usize,
+    radix: usize,
+    twiddles: &[F],
+    forward: bool,
+) {
+    let m = size / radix;
+    let omega = if forward {
+        F::root_of_unity(radix)
+    } else {
+        F::root_of_unity(radix).inv()
+    };
+    for i in 0..m {
+        let twiddles = &twiddles[i * radix..i * radix + radix];
+        for j in 0..stride {
+            let mut scratch = [F::default(); MAX_RADIX];
+            for (k, scratch) in scratch.iter_mut().enumerate().take(radix) {
+                *scratch = input[j + stride * (i + k * m)];
+            }
+            for k2 in 0..radix {
+                let base = field_pow(omega, k2);
+                let mut sum = F::default();
+                let mut wk = F::one();
+                for scratch in scratch.iter().take(radix) {
+                    sum = sum + *scratch * wk;
+                    wk = wk * base;
+                }
+                output[j + stride * (radix * i + k2)] = if k2 == 0 { sum } else { sum * twiddles[k2] };
+            }
+        }
+    }
+}
+
+/// A number-theoretic transform over a [`Field`], sharing the exact
+/// radix-4/8/4/3/2 factorization ladder [`crate::autosort::Autosort`] uses
+/// for `Complex<T>` -- the same sizes that are FFT-able are NTT-able,
+/// provided the field has a big enough root of unity.
+pub struct Ntt<F> {
+    size: usize,
+    counts: [usize; NUM_RADICES],
+    forward_twiddles: Vec<F>,
+    inverse_twiddles: Vec<F>,
+    work: RefCell<Vec<F>>,
+}
+
+impl<F: Field> Ntt<F> {
+    /// Creates a transform for the given `size`. Returns `None` if `size`
+    /// isn't 2/3/4/8-smooth. Whether `F` has a primitive `size`-th root of
+    /// unity is not checked here; [`crate::ModInt`]'s `root_of_unity` panics
+    /// when it has none.
+    pub fn new(size: usize) -> Option<Self> {
+        let counts = counts_for(size)?;
+        let (forward_twiddles, inverse_twiddles) = initialize_twiddles::<F>(size, counts);
+        Some(Self {
+            size,
+            counts,
+            forward_twiddles,
+            inverse_twiddles,
+            work: RefCell::new(vec![F::default(); size]),
+        })
+    }
+
+    /// The configured transform size.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    fn apply(&self, input: &mut [F], forward: bool) {
+        assert_eq!(input.len(), self.size);
+        let mut work = self.work.borrow_mut();
+
+        let mut twiddles: &[F] = if forward {
+            &self.forward_twiddles
+        } else {
+            &self.inverse_twiddles
+        };
+
+        let mut size = self.size;
+        let mut stride = 1;
+        let mut data_in_output = false;
+        for (radix, iterations) in RADICES.iter().zip(self.counts) {
+            for _ in 0..iterations {
+                let (from, to): (&mut _, &mut _) = if data_in_output {
+                    (work.as_mut(), input)
+                } else {
+                    (input, work.as_mut())
+                };
+                apply_stage(from, to, size, stride, *radix, twiddles, forward);
+                size /= radix;
+                stride *= radix;
+                twiddles = &twiddles[size * radix..];
+                data_in_output = !data_in_output;
+            }
+        }
+
+        if !forward {
+            let scale = F::from_usize(self.size).inv();
+            let result: &mut [F] = if data_in_output { work.as_mut() } else { input };
+            for x in result.iter_mut() {
+                *x = *x * scale;
+            }
+        }
+
+        if data_in_output {
+            input.copy_from_slice(&work);
+        }
+    }
+
+    /// Applies the forward NTT in place.
+    pub fn forward_in_place(&self, input: &mut [F]) {
+        self.apply(input, true);
+    }
+
+    /// Applies the inverse NTT in place, scaled by `1 / size`.
+    pub fn inverse_in_place(&self, input: &mut [F]) {
+        self.apply(input, false);
+    }
+}
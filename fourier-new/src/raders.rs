@@ -0,0 +1,181 @@
+use crate::{scalar::Scalar, twiddle::compute_twiddle, Fft, Real, Transform};
+use core::cell::RefCell;
+use num_complex::Complex;
+use num_traits::{Float, FromPrimitive, One};
+
+/// Returns `true` if `n` is prime, via trial division -- good enough here
+/// since this only ever runs once, at plan construction.
+pub(crate) fn is_prime(n: usize) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let mut d = 2;
+    while d * d <= n {
+        if n % d == 0 {
+            return false;
+        }
+        d += 1;
+    }
+    true
+}
+
+fn mod_pow(base: usize, mut exponent: usize, modulus: usize) -> usize {
+    let mut base = base % modulus;
+    let mut result = 1 % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Returns the smallest primitive root of the prime `n`.
+fn primitive_root(n: usize) -> usize {
+    let mut remaining = n - 1;
+    let mut factors = Vec::new();
+    let mut p = 2;
+    while p * p <= remaining {
+        if remaining % p == 0 {
+            factors.push(p);
+            while remaining % p == 0 {
+                remaining /= p;
+            }
+        }
+        p += 1;
+    }
+    if remaining > 1 {
+        factors.push(remaining);
+    }
+    (2..n)
+        .find(|&g| factors.iter().all(|&q| mod_pow(g, (n - 1) / q, n) != 1))
+        .expect("n is prime, so a primitive root always exists")
+}
+
+/// Everything [`Raders`] precomputes: the primitive root, the index
+/// permutation the multiplicative group mod `size` induces (`permutation[q]
+/// = g^q mod size`, used to gather the input), its inverse (`inverse_permutation[q]
+/// = g^-q mod size`, used both to build the convolution kernel and to scatter
+/// the output -- the same `a[q]`/`b[q]` pairing Rader's algorithm uses
+/// everywhere else in this crate family), and the forward/inverse convolution
+/// kernels already transformed by the inner FFT (i.e. `FFT(b)`), so the
+/// per-call work is just a gather, a pointwise multiply, an inverse
+/// transform, and a scatter.
+struct Configuration<T> {
+    permutation: Vec<usize>,
+    inverse_permutation: Vec<usize>,
+    b_forward: Vec<Complex<T>>,
+    b_inverse: Vec<Complex<T>>,
+}
+
+impl<T: Scalar> Configuration<T> {
+    fn new(size: usize, inner_fft: &dyn Fft<Real = T>) -> Self {
+        let root = primitive_root(size);
+        let m = size - 1;
+
+        let mut permutation = Vec::with_capacity(m);
+        let mut power = 1;
+        for _ in 0..m {
+            permutation.push(power);
+            power = power * root % size;
+        }
+        let inverse_permutation: Vec<usize> = (0..m).map(|q| permutation[(m - q) % m]).collect();
+
+        let mut b_forward: Vec<Complex<T>> = inverse_permutation
+            .iter()
+            .map(|&index| compute_twiddle(index, size, true))
+            .collect();
+        let mut b_inverse: Vec<Complex<T>> = inverse_permutation
+            .iter()
+            .map(|&index| compute_twiddle(index, size, false))
+            .collect();
+        inner_fft.fft_in_place(&mut b_forward);
+        inner_fft.fft_in_place(&mut b_inverse);
+
+        Self {
+            permutation,
+            inverse_permutation,
+            b_forward,
+            b_inverse,
+        }
+    }
+}
+
+/// Implements Rader's algorithm: for a prime `size`, the DFT is a cyclic
+/// convolution of length `size - 1`, run here through an inner FFT of
+/// exactly that length rather than [`crate::bluesteins::Bluesteins`]'s
+/// next-power-of-two chirp-z transform -- avoiding Bluestein's roughly 2x
+/// inner-transform-size overhead whenever `size - 1` itself factors nicely.
+pub struct Raders<T> {
+    size: usize,
+    configuration: Configuration<T>,
+    inner_fft: Box<dyn Fft<Real = T>>,
+    work: RefCell<Vec<Complex<T>>>,
+}
+
+impl<T: Real + Scalar> Raders<T> {
+    /// Creates a new Rader's algorithm generator. Returns `None` if `size`
+    /// is not prime.
+    pub fn new(size: usize) -> Option<Self> {
+        if size < 2 || !is_prime(size) {
+            return None;
+        }
+        let inner_fft = crate::new::<T>(size - 1);
+        let configuration = Configuration::new(size, inner_fft.as_ref());
+        let work = RefCell::new(vec![Complex::default(); size - 1]);
+        Some(Self {
+            size,
+            configuration,
+            inner_fft,
+            work,
+        })
+    }
+}
+
+impl<T: Real + Scalar> Fft for Raders<T> {
+    type Real = T;
+
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn transform_in_place(&self, input: &mut [Complex<T>], transform: Transform) {
+        let mut work = self.work.borrow_mut();
+        let b_spectrum = if transform.is_forward() {
+            &self.configuration.b_forward
+        } else {
+            &self.configuration.b_inverse
+        };
+
+        let dc = input[0];
+        let sum: Complex<T> = input.iter().fold(Complex::default(), |acc, &x| acc + x);
+
+        for (w, &index) in work.iter_mut().zip(self.configuration.permutation.iter()) {
+            *w = input[index];
+        }
+        self.inner_fft.fft_in_place(&mut work);
+        for (w, b) in work.iter_mut().zip(b_spectrum.iter()) {
+            *w *= b;
+        }
+        self.inner_fft.ifft_in_place(&mut work);
+
+        input[0] = sum;
+        for (&index, w) in self.configuration.inverse_permutation.iter().zip(work.iter()) {
+            input[index] = dc + w;
+        }
+
+        if let Some(scale) = match transform {
+            Transform::Fft | Transform::UnscaledIfft => None,
+            Transform::Ifft => Some(T::one() / T::from_usize(self.size).unwrap()),
+            Transform::SqrtScaledFft | Transform::SqrtScaledIfft => {
+                Some(T::one() / T::sqrt(T::from_usize(self.size).unwrap()))
+            }
+        } {
+            for x in input.iter_mut() {
+                *x *= scale;
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{scalar::Scalar, twiddle::compute_twiddle, Fft, Real};
+use num_complex::Complex;
+use num_traits::{Float, FromPrimitive};
+use std::cell::RefCell;
+
+/// Computes the FFT of a real-valued signal, built on top of whichever
+/// complex transform [`crate::new`] picks ([`crate::autosort::Autosort`],
+/// [`crate::raders::Raders`] or [`crate::bluesteins::Bluesteins`]): a length-`N` real signal is packed
+/// pairwise into a length-`N / 2` complex signal, transformed once, and then
+/// unpacked into the `N / 2 + 1` non-redundant complex bins via the usual
+/// even/odd split, roughly half the work of a full-length complex FFT over
+/// the real input.
+pub struct RealFft<T: Real> {
+    size: usize,
+    inner: Box<dyn Fft<Real = T>>,
+    twiddles: Vec<Complex<T>>,
+    work: RefCell<Vec<Complex<T>>>,
+}
+
+impl<T: Real + Scalar> RealFft<T> {
+    /// Creates a real FFT for a signal of the given (even) `size`.
+    pub fn new(size: usize) -> Self {
+        assert_eq!(size % 2, 0, "RealFft requires an even size");
+        let half = size / 2;
+        let inner = crate::new::<T>(half);
+        let twiddles = (0..half).map(|k| compute_twiddle(k, size, true)).collect();
+        Self {
+            size,
+            inner,
+            twiddles,
+            work: RefCell::new(vec![Complex::default(); half]),
+        }
+    }
+
+    /// The configured real signal length.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Computes the forward real FFT, producing the `size / 2 + 1`
+    /// non-redundant complex bins (the rest of the spectrum is their complex
+    /// conjugate mirror image and is never computed or stored).
+    pub fn rfft(&self, input: &[T], output: &mut [Complex<T>]) {
+        let half = self.size / 2;
+        assert_eq!(input.len(), self.size);
+        assert_eq!(output.len(), half + 1);
+
+        let half_t = T::from_f64(0.5).unwrap();
+        let mut z = self.work.borrow_mut();
+        for (z, pair) in z.iter_mut().zip(input.chunks_exact(2)) {
+            *z = Complex::new(pair[0], pair[1]);
+        }
+        self.inner.fft_in_place(&mut z);
+
+        output[0] = Complex::new(z[0].re + z[0].im, T::zero());
+        output[half] = Complex::new(z[0].re - z[0].im, T::zero());
+        for k in 1..half {
+            let even = (z[k] + z[half - k].conj()) * half_t;
+            let odd = (z[k] - z[half - k].conj()) * Complex::new(T::zero(), -half_t);
+            output[k] = even + self.twiddles[k] * odd;
+        }
+    }
+
+    /// Computes the inverse real FFT from the `size / 2 + 1` non-redundant
+    /// complex bins produced by [`rfft`](Self::rfft).
+    pub fn irfft(&self, input: &[Complex<T>], output: &mut [T]) {
+        let half = self.size / 2;
+        assert_eq!(input.len(), half + 1);
+        assert_eq!(output.len(), self.size);
+
+        let half_t = T::from_f64(0.5).unwrap();
+        let mut z = self.work.borrow_mut();
+        z[0] = Complex::new(
+            (input[0].re + input[half].re) * half_t,
+            (input[0].re - input[half].re) * half_t,
+        );
+        for k in 1..half {
+            let a = input[k];
+            let b = input[half - k].conj();
+            let even = (a + b) * half_t;
+            let odd = (a - b) * half_t * self.twiddles[k].conj();
+            // Z[k] = E[k] + i * O[k]
+            z[k] = even + Complex::new(-odd.im, odd.re);
+        }
+        self.inner.ifft_in_place(&mut z);
+
+        for (pair, z) in output.chunks_exact_mut(2).zip(z.iter()) {
+            pair[0] = z.re;
+            pair[1] = z.im;
+        }
+    }
+}
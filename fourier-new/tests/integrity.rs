@@ -194,3 +194,45 @@ generate_test! { f32, integrity_forward_f32, near_f32, true }
 generate_test! { f32, integrity_inverse_f32, near_f32, false }
 generate_test! { f64, integrity_forward_f64, near_f64, true }
 generate_test! { f64, integrity_inverse_f64, near_f64, false }
+
+macro_rules! generate_real_roundtrip_test {
+    {
+        $type:ty, $name:ident, $tolerance:expr
+    } => {
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        #[test]
+        fn $name() {
+            const MAX_SIZE: usize = 64;
+            let distribution = Normal::new(0.0, 1.0).unwrap();
+            let rng: StdRng = SeedableRng::seed_from_u64(0xdeadbeef);
+            let input = rng
+                .sample_iter(&distribution)
+                .take(MAX_SIZE)
+                .collect::<Vec<$type>>();
+            for size in (2..MAX_SIZE).step_by(2) {
+                println!("SIZE: {}", size);
+                let rfft = fourier_new::RealFft::<$type>::new(size);
+                let mut spectrum = vec![Complex::default(); size / 2 + 1];
+                let mut output = vec![<$type>::default(); size];
+                rfft.rfft(&input[0..size], &mut spectrum);
+                rfft.irfft(&spectrum, &mut output);
+                for (actual, expected) in output.iter().zip(input[0..size].iter()) {
+                    assert!(
+                        float_cmp::approx_eq!(
+                            $type,
+                            *actual,
+                            *expected,
+                            epsilon = $tolerance
+                        ),
+                        "{} != {}",
+                        actual,
+                        expected
+                    );
+                }
+            }
+        }
+    }
+}
+
+generate_real_roundtrip_test! { f32, real_roundtrip_f32, 1e-4 }
+generate_real_roundtrip_test! { f64, real_roundtrip_f64, 1e-11 }
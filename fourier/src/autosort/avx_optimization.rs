@@ -91,6 +91,73 @@ pub(crate) unsafe fn radix_4_stride_1_avx_f32(
     }
 }
 
+#[multiversion::target("[x86|x86_64]+avx")]
+#[inline]
+pub(crate) unsafe fn radix_4_stride_1_avx_f64(
+    input: &[num_complex::Complex<f64>],
+    output: &mut [num_complex::Complex<f64>],
+    forward: bool,
+    size: usize,
+    twiddles: &[num_complex::Complex<f64>],
+) {
+    avx_vector! { f64 };
+    const RADIX: usize = 4;
+    let m = size / RADIX;
+
+    for i in 0..m {
+        // Load. A `__m256d` only holds two `Complex<f64>`, half of what a
+        // `__m256` holds for `Complex<f32>`, so unlike the f32 kernel the
+        // four values of one butterfly don't fit in a single register --
+        // gather them pairwise instead, in their natural order.
+        let a = input.as_ptr().add(i).read();
+        let b = input.as_ptr().add(m + i).read();
+        let c = input.as_ptr().add(2 * m + i).read();
+        let d = input.as_ptr().add(3 * m + i).read();
+        let ab = _mm256_set_pd(b.im, b.re, a.im, a.re);
+        let cd = _mm256_set_pd(d.im, d.re, c.im, c.re);
+
+        // first radix 2
+        // sum  = ar0 ai0 ar2 ai2  (r0+r2, i0+i2, r1+r3, i1+i3)
+        // diff = ar1 ai1 ar3 ai3  (r0-r2, i0-i2, r1-r3, i1-i3)
+        let sum = _mm256_add_pd(ab, cd);
+        let diff = _mm256_sub_pd(ab, cd);
+
+        // rotate the high lane (ar3, ai3) by -i (forward) or +i (inverse),
+        // leaving the low lane (ar1, ai1) untouched
+        let diff_swapped = _mm256_permute_pd(diff, 0x5); // ai1 ar1 ai3 ar3
+        let diff_negated = _mm256_sub_pd(_mm256_setzero_pd(), diff_swapped);
+        let high_rotated = if forward {
+            _mm256_blend_pd(diff_swapped, diff_negated, 0b1000) // (ai3, -ar3)
+        } else {
+            _mm256_blend_pd(diff_swapped, diff_negated, 0b0100) // (-ai3, ar3)
+        };
+        let rotated = _mm256_blend_pd(diff, high_rotated, 0b1100);
+
+        // second radix 2: combine the low complex lane of `sum`/`rotated`
+        // with the high one
+        let sum_lo = _mm256_castpd256_pd128(sum);
+        let sum_hi = _mm256_extractf128_pd(sum, 1);
+        let rotated_lo = _mm256_castpd256_pd128(rotated);
+        let rotated_hi = _mm256_extractf128_pd(rotated, 1);
+
+        let out0 = _mm_add_pd(sum_lo, sum_hi);
+        let out2 = _mm_sub_pd(sum_lo, sum_hi);
+        let out1 = _mm_add_pd(rotated_lo, rotated_hi);
+        let out3 = _mm_sub_pd(rotated_lo, rotated_hi);
+
+        let mut out01 = _mm256_set_m128d(out1, out0);
+        let mut out23 = _mm256_set_m128d(out3, out2);
+        if size != RADIX {
+            let twiddles01 = _mm256_loadu_pd(twiddles.as_ptr().add(RADIX * i) as *const _);
+            let twiddles23 = _mm256_loadu_pd(twiddles.as_ptr().add(RADIX * i + 2) as *const _);
+            out01 = mul!(out01, twiddles01);
+            out23 = mul!(out23, twiddles23);
+        }
+        _mm256_storeu_pd(output.as_mut_ptr().add(RADIX * i) as *mut _, out01);
+        _mm256_storeu_pd(output.as_mut_ptr().add(RADIX * i + 2) as *mut _, out23);
+    }
+}
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! avx_optimization {
@@ -109,8 +176,14 @@ macro_rules! avx_optimization {
     {
         f64, $radix:literal, $input:ident, $output:ident, $forward:ident, $size:ident, $stride:ident, $twiddles:ident
     } => {
-        // TODO f64 AVX init
-        false
+        if $radix == 4 && $stride == 1 {
+            unsafe {
+                crate::autosort::avx_optimization::radix_4_stride_1_avx_f64($input, $output, $forward, $size, $twiddles);
+            }
+            true
+        } else {
+            false
+        }
     };
     {
         $type:ty, $radix:literal, $input:ident, $output:ident, $forward:ident, $size:ident, $stride:ident, $twiddles:ident
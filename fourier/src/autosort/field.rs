@@ -0,0 +1,157 @@
+use crate::float::FftFloat;
+use crate::twiddle::compute_twiddle;
+use num_complex::Complex;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// The algebraic structure the Stockham radix stages are actually built on:
+/// addition, subtraction, multiplication, negation, and a primitive `n`-th
+/// root of unity. Implemented for `Complex<T>` (the usual floating-point
+/// FFT) and for [`ModInt`] (a number-theoretic transform over a prime
+/// field), so the same twiddle-table machinery produces either one.
+pub trait Field:
+    Copy + Default + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    /// The multiplicative identity.
+    fn one() -> Self;
+
+    /// Broadcasts a `usize` into the field.
+    fn from_usize(value: usize) -> Self;
+
+    /// The multiplicative inverse, used for inverse-transform scaling and to
+    /// turn a forward root of unity into its inverse.
+    fn inv(self) -> Self;
+
+    /// A primitive `n`-th root of unity, i.e. `root_of_unity(n).pow(n) == one()`
+    /// and no smaller positive power equals `one()`.
+    fn root_of_unity(n: usize) -> Self;
+}
+
+impl<T: FftFloat> Field for Complex<T> {
+    fn one() -> Self {
+        Complex::new(T::one(), T::zero())
+    }
+
+    fn from_usize(value: usize) -> Self {
+        Complex::new(T::from_usize(value).unwrap(), T::zero())
+    }
+
+    fn inv(self) -> Self {
+        Self::one() / self
+    }
+
+    fn root_of_unity(n: usize) -> Self {
+        compute_twiddle(1, n, true)
+    }
+}
+
+/// An element of `Z/MZ` for prime `M`, used to run an exact integer
+/// number-theoretic transform instead of a floating-point FFT.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ModInt<const M: u64>(u64);
+
+impl<const M: u64> ModInt<M> {
+    pub fn new(value: u64) -> Self {
+        Self(value % M)
+    }
+
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    fn pow(self, mut exponent: u64) -> Self {
+        let mut base = self;
+        let mut result = Self(1 % M);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// Factors `M - 1` and returns the smallest `g` such that
+    /// `g^((M - 1) / p) != 1 (mod M)` for every prime factor `p` of `M - 1`,
+    /// i.e. a primitive root of `M`.
+    fn primitive_root() -> Self {
+        let mut remaining = M - 1;
+        let mut prime_factors = Vec::new();
+        let mut p = 2u64;
+        while p * p <= remaining {
+            if remaining % p == 0 {
+                prime_factors.push(p);
+                while remaining % p == 0 {
+                    remaining /= p;
+                }
+            }
+            p += 1;
+        }
+        if remaining > 1 {
+            prime_factors.push(remaining);
+        }
+
+        (2..M)
+            .map(Self::new)
+            .find(|&candidate| {
+                prime_factors
+                    .iter()
+                    .all(|&p| candidate.pow((M - 1) / p).value() != 1)
+            })
+            .expect("M is not prime, or has no primitive root")
+    }
+}
+
+impl<const M: u64> Default for ModInt<M> {
+    fn default() -> Self {
+        Self(0)
+    }
+}
+
+impl<const M: u64> Add for ModInt<M> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self((self.0 + rhs.0) % M)
+    }
+}
+
+impl<const M: u64> Sub for ModInt<M> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self((self.0 + (M - rhs.0)) % M)
+    }
+}
+
+impl<const M: u64> Mul for ModInt<M> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self(((self.0 as u128 * rhs.0 as u128) % M as u128) as u64)
+    }
+}
+
+impl<const M: u64> Neg for ModInt<M> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self((M - self.0) % M)
+    }
+}
+
+impl<const M: u64> Field for ModInt<M> {
+    fn one() -> Self {
+        Self(1 % M)
+    }
+
+    fn from_usize(value: usize) -> Self {
+        Self::new(value as u64)
+    }
+
+    fn inv(self) -> Self {
+        // `M` is prime, so Fermat's little theorem gives the inverse directly.
+        self.pow(M - 2)
+    }
+
+    fn root_of_unity(n: usize) -> Self {
+        assert_eq!((M - 1) % n as u64, 0, "n must divide M - 1");
+        Self::primitive_root().pow((M - 1) / n as u64)
+    }
+}
@@ -0,0 +1,4 @@
+pub mod field;
+pub mod ntt;
+pub mod ntt_convolve;
+pub mod prime_factor;
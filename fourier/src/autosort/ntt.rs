@@ -0,0 +1,214 @@
+use super::field::Field;
+use super::prime_factor::{initial_stage, latter_stages};
+use std::cell::Cell;
+
+/// Raises a [`Field`] element to a `usize` power by repeated squaring -- the
+/// field-generic analogue of [`crate::twiddle::compute_twiddle`]'s use of
+/// `cos`/`sin`, since a `Field` has no transcendental functions to fall back
+/// on.
+fn field_pow<F: Field>(mut base: F, mut exponent: usize) -> F {
+    let mut result = F::one();
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base;
+        }
+        base = base * base;
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Precomputed mixed-radix Stockham stage twiddles for a transform of
+/// `size`, generic over the [`Field`] the transform runs in -- this reuses
+/// the exact same radix-4/8/4/3/2 factorization ladder as
+/// [`super::prime_factor::Stages`] (via [`initial_stage`]/[`latter_stages`]),
+/// so the identical decomposition drives both the floating-point FFT and
+/// this number-theoretic transform.
+struct Stages<F> {
+    size: usize,
+    stages: Vec<(usize, usize)>,
+    forward_twiddles: Vec<F>,
+    inverse_twiddles: Vec<F>,
+}
+
+/// Field-generic counterpart of [`super::prime_factor::make_twiddles`]: the
+/// twiddles are powers of a primitive `size`-th root of unity rather than
+/// `exp(-2*pi*i*k/size)`.
+fn make_twiddles<F: Field>(mut size: usize, stages: &[(usize, usize)]) -> (Vec<F>, Vec<F>) {
+    let mut forward_twiddles = Vec::new();
+    let mut inverse_twiddles = Vec::new();
+    for (radix, count) in stages {
+        for _ in 0..*count {
+            let m = size / radix;
+            let root = F::root_of_unity(size);
+            let inverse_root = root.inv();
+            for i in 0..m {
+                let wi = field_pow(root, i);
+                let inverse_wi = field_pow(inverse_root, i);
+                let mut forward = F::one();
+                let mut inverse = F::one();
+                forward_twiddles.push(forward);
+                inverse_twiddles.push(inverse);
+                for _ in 1..*radix {
+                    forward = forward * wi;
+                    inverse = inverse * inverse_wi;
+                    forward_twiddles.push(forward);
+                    inverse_twiddles.push(inverse);
+                }
+            }
+            size /= radix;
+        }
+    }
+    (forward_twiddles, inverse_twiddles)
+}
+
+impl<F: Field> Stages<F> {
+    fn new(size: usize) -> Option<Self> {
+        let mut stages = Vec::new();
+        let current_size = initial_stage(size, &mut stages);
+        let current_size = latter_stages(8, current_size, &mut stages);
+        let current_size = latter_stages(4, current_size, &mut stages);
+        let current_size = latter_stages(3, current_size, &mut stages);
+        let current_size = latter_stages(2, current_size, &mut stages);
+        if current_size != 1 {
+            None
+        } else {
+            let (forward_twiddles, inverse_twiddles) = make_twiddles::<F>(size, &stages);
+            Some(Self {
+                size,
+                stages,
+                forward_twiddles,
+                inverse_twiddles,
+            })
+        }
+    }
+}
+
+/// The largest radix this engine's direct-DFT butterfly handles -- matches
+/// the largest radix in the factorization ladder used by [`Stages::new`].
+const MAX_RADIX: usize = 8;
+
+/// Applies one Stockham stage as a direct size-`radix` DFT butterfly. There
+/// is no field-generic equivalent of the `Complex`-specific fast radix-3/4/8
+/// butterflies in `prime_factor.rs`, since a `Field` has no `±i` rotation
+/// shortcut (see [`super::field::Field`]) -- so this falls back to an
+/// `O(radix^2)` direct sum, followed by the usual per-output twiddle
+/// multiply.
+fn apply_stage<F: Field>(
+    input: &[F],
+    output: &mut [F],
+    size: usize,
+    stride: usize,
+    radix: usize,
+    twiddles: &[F],
+    forward: bool,
+) {
+    let m = size / radix;
+    let omega = if forward {
+        F::root_of_unity(radix)
+    } else {
+        F::root_of_unity(radix).inv()
+    };
+    for i in 0..m {
+        let twiddles = &twiddles[i * radix..i * radix + radix];
+        for j in 0..stride {
+            let mut scratch = [F::default(); MAX_RADIX];
+            for k in 0..radix {
+                scratch[k] = input[j + stride * (i + k * m)];
+            }
+            for k2 in 0..radix {
+                let base = field_pow(omega, k2);
+                let mut sum = F::default();
+                let mut wk = F::one();
+                for k in 0..radix {
+                    sum = sum + scratch[k] * wk;
+                    wk = wk * base;
+                }
+                output[j + stride * (radix * i + k2)] = if k2 == 0 {
+                    sum
+                } else {
+                    sum * twiddles[k2]
+                };
+            }
+        }
+    }
+}
+
+fn apply_stages<F: Field>(input: &mut [F], output: &mut [F], stages: &Stages<F>, forward: bool) {
+    assert_eq!(input.len(), stages.size);
+    assert_eq!(output.len(), stages.size);
+
+    let mut twiddles: &[F] = if forward {
+        &stages.forward_twiddles
+    } else {
+        &stages.inverse_twiddles
+    };
+
+    let mut size = stages.size;
+    let mut stride = 1;
+    let mut data_in_output = false;
+    for (radix, iterations) in &stages.stages {
+        for _ in 0..*iterations {
+            let (from, to): (&mut _, &mut _) = if data_in_output {
+                (output, input)
+            } else {
+                (input, output)
+            };
+            apply_stage(from, to, size, stride, *radix, twiddles, forward);
+            size /= radix;
+            stride *= radix;
+            twiddles = &twiddles[size * radix..];
+            data_in_output = !data_in_output;
+        }
+    }
+
+    if !forward {
+        let scale = F::from_usize(stages.size).inv();
+        let result: &mut [F] = if data_in_output { output } else { input };
+        for x in result.iter_mut() {
+            *x = *x * scale;
+        }
+    }
+
+    if data_in_output {
+        input.copy_from_slice(output);
+    }
+}
+
+/// A number-theoretic (or, with `F = Complex<T>`, ordinary floating-point)
+/// Fourier transform over any size factorable into 2/3/4/8, built on the
+/// [`Field`] abstraction so the same mixed-radix Stockham pipeline used by
+/// [`super::prime_factor::Stages`] drives both.
+pub struct Ntt<F> {
+    stages: Stages<F>,
+    work: Cell<Box<[F]>>,
+}
+
+impl<F: Field> Ntt<F> {
+    /// Returns `None` if `size` cannot be factored into 2/3/4/8.
+    pub fn new(size: usize) -> Option<Self> {
+        let stages = Stages::new(size)?;
+        Some(Self {
+            work: Cell::new(vec![F::default(); size].into_boxed_slice()),
+            stages,
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.stages.size
+    }
+
+    pub fn forward_in_place(&self, input: &mut [F]) {
+        assert_eq!(input.len(), self.size());
+        let mut work = self.work.take();
+        apply_stages(input, &mut work, &self.stages, true);
+        self.work.set(work);
+    }
+
+    pub fn inverse_in_place(&self, input: &mut [F]) {
+        assert_eq!(input.len(), self.size());
+        let mut work = self.work.take();
+        apply_stages(input, &mut work, &self.stages, false);
+        self.work.set(work);
+    }
+}
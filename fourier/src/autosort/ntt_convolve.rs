@@ -0,0 +1,100 @@
+use super::field::{Field, ModInt};
+use super::ntt::Ntt;
+
+/// Three NTT-friendly primes of the form `c * 2^k + 1`, each supporting
+/// power-of-two transforms far larger than any convolution this planner is
+/// likely to be asked for.
+const P1: u64 = 167772161;
+const P2: u64 = 469762049;
+const P3: u64 = 998244353;
+
+fn inv_mod(value: u64, modulus: u64) -> u64 {
+    let (mut old_r, mut r) = (value as i128, modulus as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        let new_r = old_r - quotient * r;
+        old_r = r;
+        r = new_r;
+        let new_s = old_s - quotient * s;
+        old_s = s;
+        s = new_s;
+    }
+    ((old_s % modulus as i128 + modulus as i128) % modulus as i128) as u64
+}
+
+fn convolve_prime<const M: u64>(ntt: &Ntt<ModInt<M>>, size: usize, a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut x: Vec<ModInt<M>> = (0..size).map(|i| ModInt::new(*a.get(i).unwrap_or(&0))).collect();
+    let mut y: Vec<ModInt<M>> = (0..size).map(|i| ModInt::new(*b.get(i).unwrap_or(&0))).collect();
+    ntt.forward_in_place(&mut x);
+    ntt.forward_in_place(&mut y);
+    for (x, y) in x.iter_mut().zip(y.iter()) {
+        *x = *x * *y;
+    }
+    ntt.inverse_in_place(&mut x);
+    x.iter().map(|v| v.value()).collect()
+}
+
+/// Convolves sequences of `u64` coefficients under an arbitrary `u64`
+/// modulus, by running the same NTT convolution under three distinct
+/// NTT-friendly primes and reconstructing each integer coefficient with
+/// Garner's algorithm (the standard three-prime CRT trick) before the final
+/// reduction.
+///
+/// The reconstruction is exact only while each true coefficient of `a (*) b`
+/// stays below `P1 * P2 * P3` (about `2^86`). Inputs are not reduced mod
+/// `modulus` first, and a larger coefficient comes back reduced mod
+/// `P1 * P2 * P3` rather than exact.
+///
+/// Caches the three inner transforms so repeated convolutions of the same
+/// (padded) size avoid replanning.
+pub struct CrtNttPlanner {
+    size: usize,
+    ntt1: Ntt<ModInt<P1>>,
+    ntt2: Ntt<ModInt<P2>>,
+    ntt3: Ntt<ModInt<P3>>,
+    inv_p1_mod_p2: u64,
+    inv_p1p2_mod_p3: u64,
+}
+
+impl CrtNttPlanner {
+    /// Creates a planner able to convolve sequences whose result has up to
+    /// `max_result_len` coefficients.
+    pub fn new(max_result_len: usize) -> Self {
+        let size = max_result_len.next_power_of_two();
+        let p1p2_mod_p3 = (P1 as u128 * P2 as u128 % P3 as u128) as u64;
+        Self {
+            size,
+            ntt1: Ntt::new(size).expect("size is a power of two"),
+            ntt2: Ntt::new(size).expect("size is a power of two"),
+            ntt3: Ntt::new(size).expect("size is a power of two"),
+            inv_p1_mod_p2: inv_mod(P1 % P2, P2),
+            inv_p1p2_mod_p3: inv_mod(p1p2_mod_p3, P3),
+        }
+    }
+
+    /// Computes `a (*) b`, reduced modulo `modulus`.
+    pub fn convolve_mod(&self, a: &[u64], b: &[u64], modulus: u64) -> Vec<u64> {
+        let result_len = a.len() + b.len() - 1;
+        assert!(result_len <= self.size, "planner was built for a smaller result");
+
+        let r1 = convolve_prime(&self.ntt1, self.size, a, b);
+        let r2 = convolve_prime(&self.ntt2, self.size, a, b);
+        let r3 = convolve_prime(&self.ntt3, self.size, a, b);
+
+        let p1p2 = P1 as u128 * P2 as u128;
+        (0..result_len)
+            .map(|i| {
+                // Garner's algorithm: combine r1, r2 modulo p1 * p2, then fold in r3.
+                let x1 = r1[i] as u128;
+                let t2 = ((r2[i] + P2 - r1[i] % P2) % P2) as u128 * self.inv_p1_mod_p2 as u128 % P2 as u128;
+                let x12 = x1 + P1 as u128 * t2;
+                let t3 = ((r3[i] as u128 + P3 as u128 - x12 % P3 as u128) % P3 as u128)
+                    * self.inv_p1p2_mod_p3 as u128
+                    % P3 as u128;
+                let x = x12 + p1p2 * t3;
+                (x % modulus as u128) as u64
+            })
+            .collect()
+    }
+}
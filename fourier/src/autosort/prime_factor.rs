@@ -8,7 +8,7 @@ use num_complex::Complex;
 use num_traits::One;
 use std::cell::Cell;
 
-fn num_factors(factor: usize, mut value: usize) -> usize {
+pub(super) fn num_factors(factor: usize, mut value: usize) -> usize {
     let mut count = 0;
     while value % factor == 0 {
         value /= factor;
@@ -63,7 +63,7 @@ fn make_twiddles<T: FftFloat>(
 }
 
 /// Adds a stage with radix equal to the vector width, if possible
-fn initial_stage(size: usize, stages: &mut Vec<(usize, usize)>) -> usize {
+pub(super) fn initial_stage(size: usize, stages: &mut Vec<(usize, usize)>) -> usize {
     if size % 4 == 0 {
         stages.push((4, 1));
         size / 4
@@ -73,7 +73,7 @@ fn initial_stage(size: usize, stages: &mut Vec<(usize, usize)>) -> usize {
 }
 
 /// Adds as many stages as possible with the provided radix
-fn latter_stages(radix: usize, size: usize, stages: &mut Vec<(usize, usize)>) -> usize {
+pub(super) fn latter_stages(radix: usize, size: usize, stages: &mut Vec<(usize, usize)>) -> usize {
     let count = num_factors(radix, size);
     if count > 0 {
         stages.push((radix, count));
@@ -446,3 +446,88 @@ pub fn create_f64(size: usize) -> Option<impl Fft<Real = f64> + Send> {
         None
     }
 }
+
+/// An immutable, `Send + Sync` transform plan holding only the factored
+/// stages and twiddles -- unlike [`create_f32`], which bundles a
+/// `Cell`-guarded scratch buffer into the returned `Fft`, a `Plan32` owns no
+/// scratch of its own, so one plan can be shared across threads and driven
+/// concurrently as long as each caller supplies its own buffer to
+/// [`Plan32::transform_with_scratch`].
+pub struct Plan32 {
+    stages: Stages<f32>,
+    size: usize,
+}
+
+impl Plan32 {
+    /// The transform size this plan was built for.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Allocates a scratch buffer sized for this plan.
+    pub fn make_scratch(&self) -> Box<[Complex<f32>]> {
+        vec![Complex::default(); self.size].into_boxed_slice()
+    }
+
+    /// Runs `transform` on `input` in place, using `scratch` as working
+    /// space instead of an internally-owned buffer. Both slices must have
+    /// length [`Plan32::size`].
+    pub fn transform_with_scratch(
+        &self,
+        input: &mut [Complex<f32>],
+        scratch: &mut [Complex<f32>],
+        transform: Transform,
+    ) {
+        assert_eq!(input.len(), self.size);
+        assert_eq!(scratch.len(), self.size);
+        apply_stages_f32(input, scratch, &self.stages, transform);
+    }
+}
+
+/// Creates a [`Plan32`] for `size`, or `None` if `size` cannot be factored
+/// into 2/3/4/8. Like [`create_f32`], this has no Bluestein fallback; the
+/// crate's public [`crate::create_fft`] falls back to Bluestein's algorithm
+/// for other sizes.
+pub fn plan_f32(size: usize) -> Option<Plan32> {
+    Stages::new(size).map(|stages| Plan32 { stages, size })
+}
+
+/// `f64` counterpart of [`Plan32`].
+pub struct Plan64 {
+    stages: Stages<f64>,
+    size: usize,
+}
+
+impl Plan64 {
+    /// The transform size this plan was built for.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Allocates a scratch buffer sized for this plan.
+    pub fn make_scratch(&self) -> Box<[Complex<f64>]> {
+        vec![Complex::default(); self.size].into_boxed_slice()
+    }
+
+    /// Runs `transform` on `input` in place, using `scratch` as working
+    /// space instead of an internally-owned buffer. Both slices must have
+    /// length [`Plan64::size`].
+    pub fn transform_with_scratch(
+        &self,
+        input: &mut [Complex<f64>],
+        scratch: &mut [Complex<f64>],
+        transform: Transform,
+    ) {
+        assert_eq!(input.len(), self.size);
+        assert_eq!(scratch.len(), self.size);
+        apply_stages_f64(input, scratch, &self.stages, transform);
+    }
+}
+
+/// Creates a [`Plan64`] for `size`, or `None` if `size` cannot be factored
+/// into 2/3/4/8. Like [`create_f64`], this has no Bluestein fallback; the
+/// crate's public [`crate::create_fft`] falls back to Bluestein's algorithm
+/// for other sizes.
+pub fn plan_f64(size: usize) -> Option<Plan64> {
+    Stages::new(size).map(|stages| Plan64 { stages, size })
+}
@@ -117,7 +117,10 @@ fn apply<T: FftFloat>(
         }
     }
 
-    // TODO: this shouldn't be necessary...
+    // The pre/post chirp here is `exp(+i*pi*n^2/size)`, the opposite sign of
+    // the textbook Bluestein pretwiddle -- working through the convolution
+    // algebra with that sign shows the chirp-z product lands on `X[N-k]`
+    // instead of `X[k]` for every `k != 0`, so this reverses it back.
     input[1..].reverse();
 }
 
@@ -0,0 +1,280 @@
+use crate::{create_fft, Fft, Float, Transform};
+use num_complex::Complex;
+use num_traits::Float as NumFloat;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{vec, vec::Vec};
+
+/// The smallest `n >= min` whose only prime factors are 2 and 3 -- the sizes
+/// the Stockham auto-sort engine handles directly, without falling back to
+/// Bluestein's algorithm.
+fn smooth_size(min: usize) -> usize {
+    let mut n = min.max(1);
+    loop {
+        let mut m = n;
+        while m % 2 == 0 {
+            m /= 2;
+        }
+        while m % 3 == 0 {
+            m /= 3;
+        }
+        if m == 1 {
+            return n;
+        }
+        n += 1;
+    }
+}
+
+fn fft_convolve_into<T>(a: &[Complex<T>], b: &[Complex<T>], output: &mut [Complex<T>])
+where
+    T: Float + NumFloat,
+{
+    let result_len = output.len();
+    let size = smooth_size(result_len);
+    let fft = create_fft::<T>(size);
+
+    let mut x = vec![Complex::default(); size];
+    let mut y = vec![Complex::default(); size];
+    x[..a.len()].copy_from_slice(a);
+    y[..b.len()].copy_from_slice(b);
+
+    fft.fft_in_place(&mut x);
+    fft.fft_in_place(&mut y);
+    for (x, y) in x.iter_mut().zip(y.iter()) {
+        *x *= y;
+    }
+    fft.ifft_in_place(&mut x);
+
+    output.copy_from_slice(&x[..result_len]);
+}
+
+fn direct_convolve_into<T>(a: &[Complex<T>], b: &[Complex<T>], output: &mut [Complex<T>])
+where
+    T: NumFloat,
+{
+    for o in output.iter_mut() {
+        *o = Complex::default();
+    }
+    for (i, &ai) in a.iter().enumerate() {
+        for (j, &bj) in b.iter().enumerate() {
+            output[i + j] += ai * bj;
+        }
+    }
+}
+
+/// Splits `x` into two zero-padded halves of length `mid`, for Karatsuba's
+/// recursion.
+fn split_padded<T: NumFloat>(x: &[Complex<T>], mid: usize) -> (Vec<Complex<T>>, Vec<Complex<T>>) {
+    let mut lo = vec![Complex::default(); mid];
+    let mut hi = vec![Complex::default(); mid];
+    let lo_len = mid.min(x.len());
+    lo[..lo_len].copy_from_slice(&x[..lo_len]);
+    if x.len() > mid {
+        let hi_len = x.len() - mid;
+        hi[..hi_len].copy_from_slice(&x[mid..]);
+    }
+    (lo, hi)
+}
+
+/// Tunable crossover points between the direct, Karatsuba, and FFT-based
+/// convolution strategies.
+///
+/// The direct O(n*m) schoolbook loop dominates for tiny sequences (no
+/// transform setup cost), Karatsuba's recursive split wins for the next
+/// range where an FFT's fixed overhead isn't yet worth paying, and the FFT
+/// route wins asymptotically beyond that. A planner holds only the two
+/// thresholds; the FFT route builds a fresh transform on every call.
+pub struct ConvolutionPlanner<T> {
+    direct_threshold: usize,
+    karatsuba_threshold: usize,
+    _marker: core::marker::PhantomData<T>,
+}
+
+impl<T> Default for ConvolutionPlanner<T> {
+    fn default() -> Self {
+        Self::with_thresholds(32, 256)
+    }
+}
+
+impl<T> ConvolutionPlanner<T> {
+    /// Creates a planner with explicit crossover points: sequences whose
+    /// result has at most `direct_threshold` coefficients use the direct
+    /// loop, up to `karatsuba_threshold` use Karatsuba, and beyond that use
+    /// the FFT.
+    pub fn with_thresholds(direct_threshold: usize, karatsuba_threshold: usize) -> Self {
+        Self {
+            direct_threshold,
+            karatsuba_threshold,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<T> ConvolutionPlanner<T>
+where
+    T: Float + NumFloat,
+{
+    fn karatsuba_convolve_into(&self, a: &[Complex<T>], b: &[Complex<T>], output: &mut [Complex<T>]) {
+        let result_len = output.len();
+        if result_len <= self.direct_threshold {
+            direct_convolve_into(a, b, output);
+            return;
+        }
+
+        let mid = (a.len().max(b.len()) + 1) / 2;
+        let (a_lo, a_hi) = split_padded(a, mid);
+        let (b_lo, b_hi) = split_padded(b, mid);
+        let half_len = 2 * mid - 1;
+
+        let mut z0 = vec![Complex::default(); half_len];
+        self.karatsuba_convolve_into(&a_lo, &b_lo, &mut z0);
+        let mut z2 = vec![Complex::default(); half_len];
+        self.karatsuba_convolve_into(&a_hi, &b_hi, &mut z2);
+
+        let a_sum: Vec<_> = a_lo.iter().zip(a_hi.iter()).map(|(&l, &h)| l + h).collect();
+        let b_sum: Vec<_> = b_lo.iter().zip(b_hi.iter()).map(|(&l, &h)| l + h).collect();
+        let mut z1 = vec![Complex::default(); half_len];
+        self.karatsuba_convolve_into(&a_sum, &b_sum, &mut z1);
+        for i in 0..half_len {
+            z1[i] = z1[i] - z0[i] - z2[i];
+        }
+
+        for o in output.iter_mut() {
+            *o = Complex::default();
+        }
+        for (i, &z) in z0.iter().enumerate() {
+            output[i] += z;
+        }
+        for (i, &z) in z1.iter().enumerate() {
+            if mid + i < result_len {
+                output[mid + i] += z;
+            }
+        }
+        for (i, &z) in z2.iter().enumerate() {
+            if 2 * mid + i < result_len {
+                output[2 * mid + i] += z;
+            }
+        }
+    }
+
+    /// Computes the linear convolution of `a` and `b` into `output`, which
+    /// must have length `a.len() + b.len() - 1`.
+    pub fn convolve_into(&self, a: &[Complex<T>], b: &[Complex<T>], output: &mut [Complex<T>]) {
+        let result_len = a.len() + b.len() - 1;
+        assert_eq!(output.len(), result_len);
+
+        if result_len <= self.direct_threshold {
+            direct_convolve_into(a, b, output);
+        } else if result_len <= self.karatsuba_threshold {
+            self.karatsuba_convolve_into(a, b, output);
+        } else {
+            fft_convolve_into(a, b, output);
+        }
+    }
+
+    /// Computes the linear convolution of `a` and `b`.
+    pub fn convolve(&self, a: &[Complex<T>], b: &[Complex<T>]) -> Vec<Complex<T>> {
+        let mut output = vec![Complex::default(); a.len() + b.len() - 1];
+        self.convolve_into(a, b, &mut output);
+        output
+    }
+}
+
+/// Computes the linear convolution of `a` and `b` into `output`, which must
+/// have length `a.len() + b.len() - 1`, using the default crossover
+/// thresholds (see [`ConvolutionPlanner`]).
+pub fn convolve_into<T>(a: &[Complex<T>], b: &[Complex<T>], output: &mut [Complex<T>])
+where
+    T: Float + NumFloat,
+{
+    ConvolutionPlanner::default().convolve_into(a, b, output);
+}
+
+/// Computes the linear convolution of `a` and `b`, using the default
+/// crossover thresholds (see [`ConvolutionPlanner`]).
+pub fn convolve<T>(a: &[Complex<T>], b: &[Complex<T>]) -> Vec<Complex<T>>
+where
+    T: Float + NumFloat,
+{
+    ConvolutionPlanner::default().convolve(a, b)
+}
+
+/// Computes the autoconvolution `x (*) x` into `output`, which must have
+/// length `2 * x.len() - 1`.
+///
+/// This only performs a single forward transform (the spectrum is squared
+/// pointwise rather than multiplied against a second transform), so it runs
+/// two transforms where the FFT route of `convolve_into(x, x, output)` runs
+/// three. It always takes the FFT route, whatever the length.
+pub fn convolve_square_into<T>(x: &[Complex<T>], output: &mut [Complex<T>])
+where
+    T: Float + NumFloat,
+{
+    let result_len = 2 * x.len() - 1;
+    assert_eq!(output.len(), result_len);
+
+    let size = smooth_size(result_len);
+    let fft = create_fft::<T>(size);
+
+    let mut work = vec![Complex::default(); size];
+    work[..x.len()].copy_from_slice(x);
+
+    fft.fft_in_place(&mut work);
+    for w in work.iter_mut() {
+        *w = *w * *w;
+    }
+    fft.ifft_in_place(&mut work);
+
+    output.copy_from_slice(&work[..result_len]);
+}
+
+/// Computes the autoconvolution `x (*) x`.
+pub fn convolve_square<T>(x: &[Complex<T>]) -> Vec<Complex<T>>
+where
+    T: Float + NumFloat,
+{
+    let mut output = vec![Complex::default(); 2 * x.len() - 1];
+    convolve_square_into(x, &mut output);
+    output
+}
+
+/// Computes the circular (cyclic) convolution of `a` and `b` into `output`,
+/// all three of length `n := a.len()`: unlike [`convolve_into`], there is no
+/// zero-padding, so products wrap around modulo `n` instead of growing the
+/// result. The transform length is `n` itself -- handed directly to
+/// [`create_fft`], which falls back to Bluestein's algorithm if `n` isn't a
+/// Stockham-friendly size -- rather than searched via [`smooth_size`].
+pub fn circular_convolve_into<T>(a: &[Complex<T>], b: &[Complex<T>], output: &mut [Complex<T>])
+where
+    T: Float + NumFloat,
+{
+    let n = a.len();
+    assert_eq!(b.len(), n, "circular convolution requires equal-length inputs");
+    assert_eq!(output.len(), n);
+
+    let fft = create_fft::<T>(n);
+    let mut x = vec![Complex::default(); n];
+    let mut y = vec![Complex::default(); n];
+    x.copy_from_slice(a);
+    y.copy_from_slice(b);
+
+    fft.fft_in_place(&mut x);
+    fft.fft_in_place(&mut y);
+    for (x, y) in x.iter_mut().zip(y.iter()) {
+        *x *= y;
+    }
+    fft.ifft_in_place(&mut x);
+
+    output.copy_from_slice(&x);
+}
+
+/// Computes the circular (cyclic) convolution of `a` and `b`, both of
+/// length `n`, wrapping around modulo `n` (see [`circular_convolve_into`]).
+pub fn circular_convolve<T>(a: &[Complex<T>], b: &[Complex<T>]) -> Vec<Complex<T>>
+where
+    T: Float + NumFloat,
+{
+    let mut output = vec![Complex::default(); a.len()];
+    circular_convolve_into(a, b, &mut output);
+    output
+}
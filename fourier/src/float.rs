@@ -0,0 +1,6 @@
+use num_traits::{Float, FloatConst, FromPrimitive, NumAssign};
+
+/// The trait bound shared by every floating-point FFT implementation in this
+/// module (the Stockham auto-sort stages and Bluestein's algorithm).
+pub trait FftFloat: Float + FloatConst + FromPrimitive + NumAssign + Default {}
+impl<T> FftFloat for T where T: Float + FloatConst + FromPrimitive + NumAssign + Default {}
@@ -23,6 +23,37 @@ use alloc::boxed::Box;
 
 pub use fourier_algorithms::{stack_fft, Fft, Transform};
 
+mod autosort;
+mod bluesteins;
+mod float;
+mod twiddle;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod convolve;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use convolve::{
+    circular_convolve, circular_convolve_into, convolve, convolve_into, convolve_square,
+    convolve_square_into, ConvolutionPlanner,
+};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use autosort::ntt_convolve::CrtNttPlanner;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use autosort::prime_factor::{plan_f32, plan_f64, Plan32, Plan64};
+
+/// Computes `a (*) b`, the integer convolution of `a` and `b`, reduced
+/// modulo `modulus` -- a one-shot convenience wrapper around
+/// [`CrtNttPlanner`] for callers who don't need to reuse a plan across
+/// several same-size convolutions. Exact only while each true coefficient
+/// stays below the planner's three-prime bound (about `2^86`).
+///
+/// Requires the `std` or `alloc` feature.
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn convolve_mod(a: &[u64], b: &[u64], modulus: u64) -> Vec<u64> {
+    CrtNttPlanner::new(a.len() + b.len() - 1).convolve_mod(a, b, modulus)
+}
+
 /// A real scalar type that supports FFTs.
 ///
 /// Requires the `std` or `alloc` feature.
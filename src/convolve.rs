@@ -0,0 +1,234 @@
+//! High-level convolution / polynomial multiplication on top of [`Fft32`]
+//! and [`NttFft`], next to [`create_fft`].
+
+use crate::fft::{create_fft, Fft32};
+use crate::modint::ModInt;
+use crate::ntt::NttFft;
+use num_complex::Complex;
+
+/// Multiplies two complex coefficient vectors by zero-padding both to
+/// `(a.len() + b.len() - 1).next_power_of_two()`, running a forward FFT,
+/// multiplying pointwise, running an inverse FFT, and truncating to
+/// `a.len() + b.len() - 1` coefficients.
+pub fn convolve(a: &[Complex<f32>], b: &[Complex<f32>]) -> Vec<Complex<f32>> {
+    ConvolutionPlanner::new(a.len() + b.len() - 1).convolve(a, b)
+}
+
+/// Multiplies two real coefficient vectors -- i.e. multiplies the
+/// polynomials they represent -- via [`convolve`].
+pub fn polynomial_mul(a: &[f32], b: &[f32]) -> Vec<f32> {
+    let a: Vec<Complex<f32>> = a.iter().map(|&x| Complex::new(x, 0.0)).collect();
+    let b: Vec<Complex<f32>> = b.iter().map(|&x| Complex::new(x, 0.0)).collect();
+    convolve(&a, &b).iter().map(|c| c.re).collect()
+}
+
+/// A reusable convolution planner that caches an [`Fft32`] (and its
+/// twiddles) plus its work buffers for a single padded size, so repeated
+/// same-size convolutions don't rebuild them.
+pub struct ConvolutionPlanner {
+    size: usize,
+    fft: Fft32,
+    x: Vec<Complex<f32>>,
+    y: Vec<Complex<f32>>,
+}
+
+impl ConvolutionPlanner {
+    /// Creates a planner sized for convolutions whose result has up to
+    /// `result_len` coefficients.
+    pub fn new(result_len: usize) -> Self {
+        let size = result_len.max(1).next_power_of_two();
+        Self {
+            size,
+            fft: create_fft(size),
+            x: vec![Complex::default(); size],
+            y: vec![Complex::default(); size],
+        }
+    }
+
+    /// Multiplies `a` and `b`, rebuilding this planner's FFT and buffers
+    /// first if the padded size differs from the one they were built for.
+    pub fn convolve(&mut self, a: &[Complex<f32>], b: &[Complex<f32>]) -> Vec<Complex<f32>> {
+        let result_len = a.len() + b.len() - 1;
+        let mut output = vec![Complex::default(); result_len];
+        self.convolve_into(a, b, &mut output);
+        output
+    }
+
+    /// In-place variant of [`convolve`](Self::convolve): writes the
+    /// `a.len() + b.len() - 1` result coefficients into `output`, reusing
+    /// this planner's FFT and work buffers.
+    pub fn convolve_into(
+        &mut self,
+        a: &[Complex<f32>],
+        b: &[Complex<f32>],
+        output: &mut [Complex<f32>],
+    ) {
+        let result_len = a.len() + b.len() - 1;
+        assert_eq!(output.len(), result_len);
+        self.ensure_size(result_len);
+
+        for x in self.x.iter_mut() {
+            *x = Complex::default();
+        }
+        for y in self.y.iter_mut() {
+            *y = Complex::default();
+        }
+        self.x[..a.len()].copy_from_slice(a);
+        self.y[..b.len()].copy_from_slice(b);
+
+        self.fft.fft_in_place(&mut self.x);
+        self.fft.fft_in_place(&mut self.y);
+        for (x, y) in self.x.iter_mut().zip(self.y.iter()) {
+            *x *= y;
+        }
+        self.fft.ifft_in_place(&mut self.x);
+
+        output.copy_from_slice(&self.x[..result_len]);
+    }
+
+    fn ensure_size(&mut self, result_len: usize) {
+        let size = result_len.max(1).next_power_of_two();
+        if size != self.size {
+            self.size = size;
+            self.fft = create_fft(size);
+            self.x = vec![Complex::default(); size];
+            self.y = vec![Complex::default(); size];
+        }
+    }
+}
+
+/// Multiplies two coefficient vectors over `Z/pZ` via [`NttFft`], for exact
+/// integer convolution with no floating-point rounding.
+pub fn convolve_ntt<const P: u64>(a: &[ModInt<P>], b: &[ModInt<P>]) -> Vec<ModInt<P>> {
+    let result_len = a.len() + b.len() - 1;
+    let size = result_len.max(1).next_power_of_two();
+    let mut fft = NttFft::<P>::new(size);
+
+    let mut x = vec![ModInt::zero(); size];
+    let mut y = vec![ModInt::zero(); size];
+    x[..a.len()].copy_from_slice(a);
+    y[..b.len()].copy_from_slice(b);
+
+    fft.fft_in_place(&mut x);
+    fft.fft_in_place(&mut y);
+    for (x, y) in x.iter_mut().zip(y.iter()) {
+        *x = *x * *y;
+    }
+    fft.ifft_in_place(&mut x);
+
+    x.truncate(result_len);
+    x
+}
+
+/// Three NTT-friendly primes of the form `k * 2^c + 1`, used by
+/// [`convolve_mod`] for an arbitrary modulus that isn't itself NTT-friendly.
+const NTT_PRIME_0: u64 = 167772161;
+const NTT_PRIME_1: u64 = 469762049;
+const NTT_PRIME_2: u64 = 998244353;
+
+fn convolve_ntt_u64<const P: u64>(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let a: Vec<ModInt<P>> = a.iter().map(|&x| ModInt::new(x)).collect();
+    let b: Vec<ModInt<P>> = b.iter().map(|&x| ModInt::new(x)).collect();
+    convolve_ntt::<P>(&a, &b).iter().map(|x| x.value()).collect()
+}
+
+/// The modular inverse of `a` modulo `m`, via the extended Euclidean
+/// algorithm (unlike [`ModInt::inv`], `m` need not be prime).
+fn mod_inverse(a: u128, m: u128) -> u128 {
+    let (mut old_r, mut r) = (a as i128, m as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+    (old_s.rem_euclid(m as i128)) as u128
+}
+
+/// Multiplies two coefficient vectors modulo an arbitrary `modulus` (not
+/// necessarily NTT-friendly), via NTT convolution under three fixed primes
+/// ([`NTT_PRIME_0`], [`NTT_PRIME_1`], [`NTT_PRIME_2`]) followed by Garner's
+/// algorithm to reconstruct each true integer coefficient before reducing it
+/// mod `modulus`.
+///
+/// The product of the three primes is fixed (about `2^86`); whether that
+/// covers a given convolution depends on `a` and `b`'s magnitude, not just
+/// the sizes these primes support -- two arbitrary `u64`s alone can already
+/// multiply past that bound. The result is exact when `a` and `b` are
+/// residues below `modulus` and each true coefficient, at most
+/// `n * (modulus - 1)^2` for `n` terms, stays under `NTT_PRIME_0 *
+/// NTT_PRIME_1 * NTT_PRIME_2`. Only the residue condition is debug-checked
+/// below; the bound itself is not checked.
+pub fn convolve_mod(a: &[u64], b: &[u64], modulus: u64) -> Vec<u64> {
+    debug_assert!(
+        a.iter().chain(b.iter()).all(|&x| x < modulus),
+        "convolve_mod's inputs must already be residues below `modulus`"
+    );
+    let r0 = convolve_ntt_u64::<NTT_PRIME_0>(a, b);
+    let r1 = convolve_ntt_u64::<NTT_PRIME_1>(a, b);
+    let r2 = convolve_ntt_u64::<NTT_PRIME_2>(a, b);
+
+    let p0 = NTT_PRIME_0 as u128;
+    let p1 = NTT_PRIME_1 as u128;
+    let p2 = NTT_PRIME_2 as u128;
+    let modulus = modulus as u128;
+
+    let inv_p0_mod_p1 = mod_inverse(p0 % p1, p1);
+    let inv_p0_mod_p2 = mod_inverse(p0 % p2, p2);
+    let inv_p1_mod_p2 = mod_inverse(p1 % p2, p2);
+
+    let p0_mod_m = p0 % modulus;
+    let p0p1_mod_m = (p0 * p1) % modulus;
+
+    r0.iter()
+        .zip(r1.iter())
+        .zip(r2.iter())
+        .map(|((&r0, &r1), &r2)| {
+            let (r0, r1, r2) = (r0 as u128, r1 as u128, r2 as u128);
+
+            // Garner's algorithm: x = x0 + x1*p0 + x2*p0*p1, with each xi
+            // found by solving the next residue equation modulo its prime.
+            let x0 = r0;
+            let x1 = ((r1 + p1 - x0 % p1) % p1 * inv_p0_mod_p1) % p1;
+            let x2_a = ((r2 + p2 - x0 % p2) % p2 * inv_p0_mod_p2) % p2;
+            let x2 = ((x2_a + p2 - x1) % p2 * inv_p1_mod_p2) % p2;
+
+            (x0 % modulus + (x1 * p0_mod_m) % modulus + (x2 * p0p1_mod_m) % modulus) % modulus
+        })
+        .map(|x| x as u64)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn schoolbook_convolve_mod(a: &[u64], b: &[u64], modulus: u64) -> Vec<u64> {
+        let mut result = vec![0u128; a.len() + b.len() - 1];
+        for (i, &ai) in a.iter().enumerate() {
+            for (j, &bj) in b.iter().enumerate() {
+                result[i + j] += ai as u128 * bj as u128;
+            }
+        }
+        result
+            .into_iter()
+            .map(|x| (x % modulus as u128) as u64)
+            .collect()
+    }
+
+    #[test]
+    fn convolve_mod_matches_schoolbook_near_bound() {
+        // `modulus` close to `u32::MAX`, so the true, un-reduced coefficients
+        // (up to about `2^67` here) overflow `u64` while staying under
+        // `NTT_PRIME_0 * NTT_PRIME_1 * NTT_PRIME_2` (about `2^86`) -- the
+        // range `convolve_mod`'s doc comment states is exact.
+        let modulus: u64 = (1u64 << 32) - 5;
+        let n = 1usize << 10;
+        let a: Vec<u64> = (0..n as u64).map(|i| i.wrapping_mul(2654435761) % modulus).collect();
+        let b: Vec<u64> = (0..n as u64).map(|i| i.wrapping_mul(40503).wrapping_add(7) % modulus).collect();
+
+        let expected = schoolbook_convolve_mod(&a, &b, modulus);
+        let actual = convolve_mod(&a, &b, modulus);
+        assert_eq!(actual, expected);
+    }
+}
@@ -23,7 +23,7 @@ fn apply_f32(operation: &Operation<f32>, input: &[Complex<f32>], output: &mut [C
 //#[target_clones("x86_64+avx")]
 #[inline]
 fn forward_f32_in_place(
-    operations: &Vec<Operation<f32>>,
+    operations: &[Operation<f32>],
     input: &mut [Complex<f32>],
     work: &mut [Complex<f32>],
 ) {
@@ -47,7 +47,7 @@ fn forward_f32_in_place(
 //#[target_clones("x86_64+avx")]
 #[inline]
 fn inverse_f32_in_place(
-    operations: &Vec<Operation<f32>>,
+    operations: &[Operation<f32>],
     input: &mut [Complex<f32>],
     work: &mut [Complex<f32>],
 ) {
@@ -75,6 +75,11 @@ fn inverse_f32_in_place(
     }
 }
 
+/// Creates a new FFT for the given size, next to [`Fft32::new`].
+pub fn create_fft(size: usize) -> Fft32 {
+    Fft32::new(size)
+}
+
 pub struct Fft32 {
     size: usize,
     forward_ops: Vec<Operation<f32>>,
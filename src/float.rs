@@ -1,4 +1,38 @@
-use num_traits::{Float, FloatConst, FromPrimitive, NumAssign};
+use num_complex::Complex;
+use num_traits::{Float, FloatConst, FromPrimitive, MulAdd, NumAssign};
 
-pub trait FftFloat: Float + FloatConst + FromPrimitive + NumAssign + Default + Clone {}
-impl<T> FftFloat for T where T: Float + FloatConst + FromPrimitive + NumAssign + Default + Clone {}
+pub trait FftFloat:
+    Float + FloatConst + FromPrimitive + NumAssign + MulAdd<Output = Self> + Default + Clone
+{
+}
+impl<T> FftFloat for T where
+    T: Float + FloatConst + FromPrimitive + NumAssign + MulAdd<Output = Self> + Default + Clone
+{
+}
+
+/// Complex multiply that fuses each cross term's multiply with the following
+/// add/subtract, so targets with hardware FMA (ARM, RISC-V, ...) pick it up
+/// even outside the AVX-specific paths.
+#[inline]
+pub fn cmul<T: FftFloat>(a: Complex<T>, b: Complex<T>) -> Complex<T> {
+    Complex::new(a.re.mul_add(b.re, -(a.im * b.im)), a.re.mul_add(b.im, a.im * b.re))
+}
+
+/// The single twiddle factor implementation shared by every radix and by
+/// `RealFft` (`Bluestein` computes its half-angle chirp separately). Uses `core::f64` rather than `std::f64` and reaches `sin`/
+/// `cos` through `Float`, so this crate builds `#![no_std]` as long as the
+/// `libm` feature (forwarded to `num-traits`/`num-complex`) is enabled to
+/// provide those trig functions without the standard library.
+#[inline]
+pub fn compute_twiddle<T: FftFloat>(index: usize, size: usize, forward: bool) -> Complex<T> {
+    let theta = (index * 2) as f64 * core::f64::consts::PI / size as f64;
+    let twiddle = Complex::new(
+        T::from_f64(Float::cos(theta)).unwrap(),
+        T::from_f64(-Float::sin(theta)).unwrap(),
+    );
+    if forward {
+        twiddle
+    } else {
+        twiddle.conj()
+    }
+}
@@ -0,0 +1,160 @@
+//! Modular integer arithmetic for number-theoretic transforms (NTT).
+//!
+//! Mirrors `crate::float::{compute_twiddle, FftFloat}`, but for `Z/pZ` rather
+//! than `Complex<f32/f64>`: [`ModInt`] stands in for `Complex<T>`, and
+//! [`compute_twiddle`] returns a power of a primitive root instead of a point
+//! on the unit circle.
+
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// An element of `Z/pZ` for a prime modulus `P`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModInt<const P: u64>(u64);
+
+impl<const P: u64> ModInt<P> {
+    pub fn new(value: u64) -> Self {
+        Self(value % P)
+    }
+
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    pub fn zero() -> Self {
+        Self(0)
+    }
+
+    pub fn one() -> Self {
+        Self(1 % P)
+    }
+
+    /// Raises `self` to the `exponent` power by repeated squaring.
+    pub fn pow(self, mut exponent: u64) -> Self {
+        let mut base = self;
+        let mut result = Self::one();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// The multiplicative inverse of `self`.  `P` is assumed prime, so Fermat's
+    /// little theorem gives `self^-1 == self^(P - 2) mod P`.
+    pub fn inv(self) -> Self {
+        self.pow(P - 2)
+    }
+}
+
+impl<const P: u64> Default for ModInt<P> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<const P: u64> Add for ModInt<P> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let sum = self.0 + rhs.0;
+        Self(if sum >= P { sum - P } else { sum })
+    }
+}
+
+impl<const P: u64> Sub for ModInt<P> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self(if self.0 >= rhs.0 { self.0 - rhs.0 } else { self.0 + P - rhs.0 })
+    }
+}
+
+impl<const P: u64> Neg for ModInt<P> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(if self.0 == 0 { 0 } else { P - self.0 })
+    }
+}
+
+impl<const P: u64> Mul for ModInt<P> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self(((self.0 as u128 * rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+/// Primitive roots for NTT-friendly primes of the form `k * 2^c + 1` that are
+/// common enough to skip [`find_primitive_root`]'s factorization.
+fn fast_path_primitive_root(p: u64) -> Option<u64> {
+    match p {
+        998244353 => Some(3),
+        167772161 => Some(3),
+        469762049 => Some(3),
+        754974721 => Some(11),
+        _ => None,
+    }
+}
+
+fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1u128;
+    let mut base = base as u128 % modulus as u128;
+    let modulus = modulus as u128;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result as u64
+}
+
+/// Finds a primitive root of the prime `p`, by factoring `p - 1` and testing
+/// candidates `g` such that `g^((p-1)/q) != 1` for every prime factor `q` of
+/// `p - 1`.
+fn find_primitive_root(p: u64) -> u64 {
+    let mut factors = Vec::new();
+    let mut n = p - 1;
+    let mut d = 2;
+    while d * d <= n {
+        if n % d == 0 {
+            factors.push(d);
+            while n % d == 0 {
+                n /= d;
+            }
+        }
+        d += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+
+    'candidate: for g in 2..p {
+        for &q in &factors {
+            if mod_pow(g, (p - 1) / q, p) == 1 {
+                continue 'candidate;
+            }
+        }
+        return g;
+    }
+    unreachable!("every prime has a primitive root")
+}
+
+/// Returns a primitive root of the prime `P`, using [`fast_path_primitive_root`]
+/// for common NTT primes before falling back to [`find_primitive_root`].
+pub fn primitive_root<const P: u64>() -> u64 {
+    fast_path_primitive_root(P).unwrap_or_else(|| find_primitive_root(P))
+}
+
+/// The modular analogue of `crate::float::compute_twiddle`: returns
+/// `root^index`, where `root` is a primitive `size`-th root of unity modulo
+/// `P` (i.e. `g^((P-1)/size)` for a primitive root `g` of `P`). `size` must
+/// divide `P - 1`. The inverse transform uses `root^-1` in place of `root`.
+pub fn compute_twiddle<const P: u64>(index: usize, size: usize, forward: bool) -> ModInt<P> {
+    assert_eq!((P - 1) % size as u64, 0, "size must divide p - 1");
+    let g = ModInt::<P>::new(primitive_root::<P>());
+    let root = g.pow((P - 1) / size as u64);
+    let root = if forward { root } else { root.inv() };
+    root.pow(index as u64)
+}
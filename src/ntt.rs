@@ -0,0 +1,180 @@
+//! Number-theoretic transform (NTT) over `Z/pZ`, generalizing the radix-2/
+//! radix-3 machinery in [`crate::operations`] (`BaseConfig`, `compute_twiddle`)
+//! from `Complex<f32/f64>` to [`ModInt`]. Twiddles become powers of a
+//! primitive root rather than points on the unit circle; the inverse
+//! transform scales by `n^-1 mod p` instead of `1/n`. Mirrors the driver
+//! shape of [`crate::fft::Fft32`], splitting the size into radix-2/radix-3
+//! stages and falling back to `unimplemented!` for any remaining prime
+//! factor, since NTT sizes in practice are chosen to be smooth.
+
+use crate::modint::{compute_twiddle, ModInt};
+
+struct BaseConfig<const P: u64> {
+    twiddles: Vec<ModInt<P>>,
+    stride: usize,
+    size: usize,
+}
+
+impl<const P: u64> BaseConfig<P> {
+    fn new(size: usize, stride: usize, radix: usize, forward: bool) -> Self {
+        assert_eq!(size % radix, 0);
+        let m = size / radix;
+        let mut twiddles = Vec::new();
+        for i in 1..radix {
+            for j in 0..m {
+                twiddles.push(compute_twiddle::<P>(i * j, size, forward));
+            }
+        }
+        Self {
+            twiddles,
+            stride,
+            size,
+        }
+    }
+
+    fn forward(size: usize, stride: usize, radix: usize) -> Self {
+        Self::new(size, stride, radix, true)
+    }
+
+    fn inverse(size: usize, stride: usize, radix: usize) -> Self {
+        Self::new(size, stride, radix, false)
+    }
+}
+
+enum Operation<const P: u64> {
+    Radix2(BaseConfig<P>),
+    Radix3 { base: BaseConfig<P>, twiddle: ModInt<P> },
+}
+
+#[inline]
+fn radix2<const P: u64>(x: &[ModInt<P>], y: &mut [ModInt<P>], config: &BaseConfig<P>) {
+    let BaseConfig {
+        twiddles,
+        stride,
+        size,
+    } = config;
+    assert_eq!(x.len(), size * stride);
+    assert_eq!(y.len(), size * stride);
+    assert!(*stride != 0);
+
+    let m = size / 2;
+    for i in 0..m {
+        let wi = twiddles[i];
+        for j in 0..*stride {
+            let a = x[j + stride * i];
+            let b = x[j + stride * (i + m)];
+            y[j + stride * 2 * i] = a + b;
+            y[j + stride * (2 * i + 1)] = (a - b) * wi;
+        }
+    }
+}
+
+#[inline]
+fn radix3<const P: u64>(
+    x: &[ModInt<P>],
+    y: &mut [ModInt<P>],
+    base: &BaseConfig<P>,
+    twiddle: ModInt<P>,
+) {
+    let BaseConfig {
+        twiddles,
+        stride,
+        size,
+    } = base;
+    assert_eq!(x.len(), size * stride);
+    assert_eq!(y.len(), size * stride);
+    assert!(*stride != 0);
+
+    let twiddle2 = twiddle * twiddle;
+    let m = size / 3;
+    for i in 0..m {
+        let wi = twiddles[i];
+        let wi2 = twiddles[m + i];
+        for j in 0..*stride {
+            let a = x[j + stride * i];
+            let b = x[j + stride * (i + m)];
+            let c = x[j + stride * (i + 2 * m)];
+            y[j + stride * 3 * i] = a + b + c;
+            y[j + stride * (3 * i + 1)] = (a + b * twiddle + c * twiddle2) * wi;
+            y[j + stride * (3 * i + 2)] = (a + b * twiddle2 + c * twiddle) * wi2;
+        }
+    }
+}
+
+fn run<const P: u64>(ops: &[Operation<P>], input: &mut [ModInt<P>], work: &mut [ModInt<P>]) {
+    let mut data_in_work = false;
+    for op in ops {
+        let (from, to): (&mut _, &mut _) = if data_in_work {
+            (work, input)
+        } else {
+            (input, work)
+        };
+        match op {
+            Operation::Radix2(base) => radix2(from, to, base),
+            Operation::Radix3 { base, twiddle } => radix3(from, to, base, *twiddle),
+        }
+        data_in_work ^= true;
+    }
+    if data_in_work {
+        input.copy_from_slice(work);
+    }
+}
+
+/// An NTT over `Z/pZ`, for sizes composed only of factors of 2 and 3 (the
+/// same radix restriction as [`crate::fft::Fft32`]).
+pub struct NttFft<const P: u64> {
+    size: usize,
+    forward_ops: Vec<Operation<P>>,
+    inverse_ops: Vec<Operation<P>>,
+    work: Vec<ModInt<P>>,
+}
+
+impl<const P: u64> NttFft<P> {
+    pub fn new(size: usize) -> Self {
+        let mut forward_ops = Vec::new();
+        let mut inverse_ops = Vec::new();
+        let mut subsize = size;
+        let mut stride = 1usize;
+        while subsize != 1 {
+            if subsize % 2 == 0 {
+                forward_ops.push(Operation::Radix2(BaseConfig::forward(subsize, stride, 2)));
+                inverse_ops.push(Operation::Radix2(BaseConfig::inverse(subsize, stride, 2)));
+                subsize /= 2;
+                stride *= 2;
+            } else if subsize % 3 == 0 {
+                forward_ops.push(Operation::Radix3 {
+                    base: BaseConfig::forward(subsize, stride, 3),
+                    twiddle: compute_twiddle::<P>(1, 3, true),
+                });
+                inverse_ops.push(Operation::Radix3 {
+                    base: BaseConfig::inverse(subsize, stride, 3),
+                    twiddle: compute_twiddle::<P>(1, 3, false),
+                });
+                subsize /= 3;
+                stride *= 3;
+            } else {
+                unimplemented!("only sizes with factors of 2 and 3 are supported");
+            }
+        }
+        Self {
+            size,
+            forward_ops,
+            inverse_ops,
+            work: vec![ModInt::zero(); size],
+        }
+    }
+
+    pub fn fft_in_place(&mut self, input: &mut [ModInt<P>]) {
+        assert_eq!(input.len(), self.size, "input must match configured size");
+        run(&self.forward_ops, input, &mut self.work);
+    }
+
+    pub fn ifft_in_place(&mut self, input: &mut [ModInt<P>]) {
+        assert_eq!(input.len(), self.size, "input must match configured size");
+        run(&self.inverse_ops, input, &mut self.work);
+        let n_inv = ModInt::<P>::new(self.size as u64).inv();
+        for x in input.iter_mut() {
+            *x = *x * n_inv;
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use super::{apply, get_operations, Operation};
+use crate::float::FftFloat;
+use num_complex::Complex;
+
+fn chirp<T: FftFloat>(n: usize, size: usize, forward: bool) -> Complex<T> {
+    let theta = T::PI() * T::from_usize(n * n % (2 * size)).unwrap() / T::from_usize(size).unwrap();
+    let twiddle = Complex::new(theta.cos(), -theta.sin());
+    if forward {
+        twiddle
+    } else {
+        twiddle.conj()
+    }
+}
+
+fn run_forward<T: FftFloat>(ops: &[Operation<T>], input: &mut [Complex<T>], work: &mut [Complex<T>]) {
+    let mut data_in_work = false;
+    for op in ops {
+        let (from, to): (&mut _, &mut _) = if data_in_work { (work, input) } else { (input, work) };
+        apply(op, from, to);
+        data_in_work ^= true;
+    }
+    if data_in_work {
+        input.copy_from_slice(work);
+    }
+}
+
+fn run_inverse<T: FftFloat>(ops: &[Operation<T>], input: &mut [Complex<T>], work: &mut [Complex<T>]) {
+    run_forward(ops, input, work);
+    let scale = T::from_usize(input.len()).unwrap();
+    for x in input.iter_mut() {
+        *x /= scale;
+    }
+}
+
+pub struct Bluestein<T> {
+    size: usize,
+    stride: usize,
+    m: usize,
+    chirp: Vec<Complex<T>>,
+    kernel: Vec<Complex<T>>,
+    inner_forward: Vec<Operation<T>>,
+    inner_inverse: Vec<Operation<T>>,
+}
+
+impl<T: FftFloat> Bluestein<T> {
+    fn new(size: usize, stride: usize, forward: bool) -> Self {
+        let m = (2 * size - 1).next_power_of_two();
+        let chirp: Vec<Complex<T>> = (0..size).map(|n| chirp(n, size, forward)).collect();
+
+        let mut kernel = vec![Complex::default(); m];
+        kernel[0] = chirp[0].conj();
+        for n in 1..size {
+            let b = chirp[n].conj();
+            kernel[n] = b;
+            kernel[m - n] = b;
+        }
+
+        let (inner_forward, inner_inverse) = get_operations::<T>(m);
+        let mut work = vec![Complex::default(); m];
+        run_forward(&inner_forward, &mut kernel, &mut work);
+
+        Self {
+            size,
+            stride,
+            m,
+            chirp,
+            kernel,
+            inner_forward,
+            inner_inverse,
+        }
+    }
+
+    pub fn forward(size: usize, stride: usize) -> Self {
+        Self::new(size, stride, true)
+    }
+
+    pub fn inverse(size: usize, stride: usize) -> Self {
+        Self::new(size, stride, false)
+    }
+}
+
+#[inline]
+pub fn bluestein<T: FftFloat>(x: &[Complex<T>], y: &mut [Complex<T>], config: &Bluestein<T>) {
+    let Bluestein {
+        size,
+        stride,
+        m,
+        chirp,
+        kernel,
+        inner_forward,
+        inner_inverse,
+    } = config;
+    assert_eq!(x.len(), size * stride);
+    assert_eq!(y.len(), size * stride);
+
+    let mut a = vec![Complex::default(); *m];
+    let mut work = vec![Complex::default(); *m];
+    for j in 0..*stride {
+        for n in 0..*size {
+            a[n] = crate::float::cmul(x[j + stride * n], chirp[n]);
+        }
+        for n in *size..*m {
+            a[n] = Complex::default();
+        }
+        run_forward(inner_forward, &mut a, &mut work);
+        for (a, k) in a.iter_mut().zip(kernel.iter()) {
+            *a = crate::float::cmul(*a, *k);
+        }
+        run_inverse(inner_inverse, &mut a, &mut work);
+        for k in 0..*size {
+            y[j + stride * k] = crate::float::cmul(a[k], chirp[k]);
+        }
+    }
+}
+
+pub fn bluestein_f32(x: &[Complex<f32>], y: &mut [Complex<f32>], config: &Bluestein<f32>) {
+    bluestein(x, y, config);
+}
@@ -1,23 +1,14 @@
-use crate::float::FftFloat;
+use crate::float::{compute_twiddle, FftFloat};
 use num_complex::Complex;
 
+mod bluestein;
 mod radix2;
 mod radix3;
+mod real;
+use bluestein::*;
 use radix2::*;
 use radix3::*;
-
-fn compute_twiddle<T: FftFloat>(index: usize, size: usize, forward: bool) -> Complex<T> {
-    let theta = (index * 2) as f64 * std::f64::consts::PI / size as f64;
-    let twiddle = Complex::new(
-        T::from_f64(theta.cos()).unwrap(),
-        T::from_f64(-theta.sin()).unwrap(),
-    );
-    if forward {
-        twiddle
-    } else {
-        twiddle.conj()
-    }
-}
+pub use real::RealFft;
 
 struct BaseConfig<T> {
     twiddles: Vec<Complex<T>>,
@@ -53,10 +44,11 @@ impl<T: FftFloat> BaseConfig<T> {
 
 macro_rules! operations {
     {
-        $([radix $radix:literal => $operation:ident, $f32_op:ident]),*
+        $([radix $radix:literal => $operation:ident, $generic_op:ident, $f32_op:ident]),*
     } => {
         pub enum Operation<T: FftFloat> {
-            $($operation($operation<T>)),*
+            $($operation($operation<T>)),*,
+            Bluestein(Bluestein<T>),
         }
 
         pub fn get_operations<T: FftFloat>(size: usize) -> (Vec<Operation<T>>, Vec<Operation<T>>) {
@@ -74,7 +66,13 @@ macro_rules! operations {
                         continue;
                     }
                 )*
-                unimplemented!("unsupported radix");
+                // No supported radix divides the remaining size (e.g. it's prime, or has a
+                // prime factor other than 2 or 3). Fall back to Bluestein's algorithm, which
+                // handles any remaining size in a single pass by reducing it to a convolution.
+                forward_ops.push(Operation::Bluestein(Bluestein::forward(subsize, stride)));
+                inverse_ops.push(Operation::Bluestein(Bluestein::inverse(subsize, stride)));
+                stride *= subsize;
+                subsize = 1;
             }
             (forward_ops, inverse_ops)
         }
@@ -83,21 +81,30 @@ macro_rules! operations {
         #[inline]
         fn apply_f32(operation: &Operation<f32>, input: &[Complex<f32>], output: &mut [Complex<f32>]) {
             match operation {
-                $(Operation::$operation(config) => $f32_op(input, output, config)),*
+                $(Operation::$operation(config) => $f32_op(input, output, config),)*
+                Operation::Bluestein(config) => bluestein_f32(input, output, config),
+            }
+        }
+
+        #[inline]
+        fn apply<T: FftFloat>(operation: &Operation<T>, input: &[Complex<T>], output: &mut [Complex<T>]) {
+            match operation {
+                $(Operation::$operation(config) => $generic_op(input, output, config),)*
+                Operation::Bluestein(config) => bluestein(input, output, config),
             }
         }
     }
 }
 
 operations! {
-    [radix 3 => Radix3, radix3_f32],
-    [radix 2 => Radix2, radix2_f32]
+    [radix 3 => Radix3, radix3, radix3_f32],
+    [radix 2 => Radix2, radix2, radix2_f32]
 }
 
 //#[target_clones("x86_64+avx")]
 #[inline]
 pub fn forward_f32_in_place(
-    operations: &Vec<Operation<f32>>,
+    operations: &[Operation<f32>],
     input: &mut [Complex<f32>],
     work: &mut [Complex<f32>],
 ) {
@@ -121,7 +128,7 @@ pub fn forward_f32_in_place(
 //#[target_clones("x86_64+avx")]
 #[inline]
 pub fn inverse_f32_in_place(
-    operations: &Vec<Operation<f32>>,
+    operations: &[Operation<f32>],
     input: &mut [Complex<f32>],
     work: &mut [Complex<f32>],
 ) {
@@ -1,5 +1,5 @@
 use super::BaseConfig;
-use crate::float::FftFloat;
+use crate::float::{cmul, FftFloat};
 use num_complex::Complex;
 
 pub struct Radix2<T> {
@@ -43,7 +43,7 @@ pub fn radix2<T: FftFloat>(
             let a = x[j + stride * i];
             let b = x[j + stride * (i + m)];
             y[j + stride * 2 * i] = a + b;
-            y[j + stride * (2 * i + 1)] = (a - b) * wi;
+            y[j + stride * (2 * i + 1)] = cmul(a - b, wi);
         }
     }
 }
@@ -0,0 +1,111 @@
+use super::{compute_twiddle, get_operations, Operation};
+use crate::float::{cmul, FftFloat};
+use num_complex::Complex;
+
+fn run_forward<T: FftFloat>(ops: &[Operation<T>], input: &mut [Complex<T>], work: &mut [Complex<T>]) {
+    let mut data_in_work = false;
+    for op in ops {
+        let (from, to): (&mut _, &mut _) = if data_in_work { (work, input) } else { (input, work) };
+        super::apply(op, from, to);
+        data_in_work ^= true;
+    }
+    if data_in_work {
+        input.copy_from_slice(work);
+    }
+}
+
+fn run_inverse<T: FftFloat>(ops: &[Operation<T>], input: &mut [Complex<T>], work: &mut [Complex<T>]) {
+    run_forward(ops, input, work);
+    let scale = T::from_usize(input.len()).unwrap();
+    for x in input.iter_mut() {
+        *x /= scale;
+    }
+}
+
+/// A real-to-complex (and complex-to-real) transform, built on top of a
+/// half-size complex transform plus a recombination pass.
+pub struct RealFft<T> {
+    size: usize,
+    forward_ops: Vec<Operation<T>>,
+    inverse_ops: Vec<Operation<T>>,
+    // `compute_twiddle(k, size, true)` for `k` in `0..=size / 2`.
+    twiddles: Vec<Complex<T>>,
+}
+
+impl<T: FftFloat> RealFft<T> {
+    pub fn new(size: usize) -> Self {
+        assert_eq!(size % 2, 0, "RealFft requires an even size");
+        let half = size / 2;
+        let (forward_ops, inverse_ops) = get_operations::<T>(half);
+        let twiddles = (0..=half).map(|k| compute_twiddle(k, size, true)).collect();
+        Self {
+            size,
+            forward_ops,
+            inverse_ops,
+            twiddles,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Computes the `size / 2 + 1` non-redundant complex bins of the real input.
+    pub fn rfft(&self, input: &[T], output: &mut [Complex<T>]) {
+        let half = self.size / 2;
+        assert_eq!(input.len(), self.size);
+        assert_eq!(output.len(), half + 1);
+
+        let mut z: Vec<Complex<T>> = (0..half)
+            .map(|n| Complex::new(input[2 * n], input[2 * n + 1]))
+            .collect();
+        let mut work = vec![Complex::default(); half];
+        run_forward(&self.forward_ops, &mut z, &mut work);
+
+        output[0] = Complex::new(z[0].re + z[0].im, T::zero());
+        output[half] = Complex::new(z[0].re - z[0].im, T::zero());
+
+        let half_t = T::from_f64(0.5).unwrap();
+        let neg_i = Complex::new(T::zero(), -T::one());
+        for k in 1..half {
+            let zk = z[k];
+            let zm_conj = z[half - k].conj();
+            let a = (zk + zm_conj) * half_t;
+            let b = (zk - zm_conj) * half_t;
+            output[k] = a + cmul(neg_i, cmul(self.twiddles[k], b));
+        }
+    }
+
+    /// Reconstructs a real signal from its non-redundant spectrum.
+    pub fn irfft(&self, input: &[Complex<T>], output: &mut [T]) {
+        let half = self.size / 2;
+        assert_eq!(input.len(), half + 1);
+        assert_eq!(output.len(), self.size);
+
+        let half_t = T::from_f64(0.5).unwrap();
+        let neg_i = Complex::new(T::zero(), -T::one());
+
+        let mut z = vec![Complex::default(); half];
+        z[0] = Complex::new(
+            (input[0].re + input[half].re) * half_t,
+            (input[0].re - input[half].re) * half_t,
+        );
+        for k in 1..half {
+            let c = cmul(neg_i, self.twiddles[k]);
+            let c_conj_m = cmul(neg_i, self.twiddles[half - k]).conj();
+            // Solve { a + c*b = Zk, a - conj(c_m)*b = conj(Zm) } for a, b.
+            let zk = input[k];
+            let zm_conj = input[half - k].conj();
+            let b = (zk - zm_conj) / (c + c_conj_m);
+            let a = zk - cmul(c, b);
+            z[k] = a + b;
+        }
+
+        let mut work = vec![Complex::default(); half];
+        run_inverse(&self.inverse_ops, &mut z, &mut work);
+        for (n, zn) in z.iter().enumerate() {
+            output[2 * n] = zn.re;
+            output[2 * n + 1] = zn.im;
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::float::FftFloat;
+use crate::float::{compute_twiddle, FftFloat};
 use num_complex::Complex;
 
 mod radix2;
@@ -6,19 +6,6 @@ mod radix3;
 pub use radix2::*;
 pub use radix3::*;
 
-fn compute_twiddle<T: FftFloat>(index: usize, size: usize, forward: bool) -> Complex<T> {
-    let theta = (index * 2) as f64 * std::f64::consts::PI / size as f64;
-    let twiddle = Complex::new(
-        T::from_f64(theta.cos()).unwrap(),
-        T::from_f64(-theta.sin()).unwrap(),
-    );
-    if forward {
-        twiddle
-    } else {
-        twiddle.conj()
-    }
-}
-
 #[derive(Debug)]
 struct BaseConfig<T> {
     twiddles: Vec<Complex<T>>,